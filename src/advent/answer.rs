@@ -0,0 +1,83 @@
+use std::fmt::Display;
+
+/// The result of a single part. Most puzzles produce a number, but a few
+/// need more than 64 bits or are not numeric at all.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    Big(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::Big(x) => write!(f, "{}", x),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(x: i64) -> Self {
+        Answer::Int(x)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(x: i128) -> Self {
+        match i64::try_from(x) {
+            Ok(x) => Answer::Int(x),
+            Err(_) => Answer::Big(x),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(x: i32) -> Self {
+        Answer::Int(x as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(x: u32) -> Self {
+        Answer::Int(x as i64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(x: u64) -> Self {
+        Answer::from(x as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(x: usize) -> Self {
+        Answer::from(x as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+/// Answers that fit in 64 bits become JSON numbers. Bigger ones become strings,
+/// since most JSON readers cannot hold them exactly.
+impl From<&Answer> for serde_json::Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(x) => serde_json::Value::from(*x),
+            Answer::Big(x) => serde_json::Value::from(x.to_string()),
+            Answer::Text(s) => serde_json::Value::from(s.as_str()),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::path::Path;
+
+use super::log::{self, Level};
+use super::runner::Run;
+use super::{Answer, Error, DEFAULT_YEAR};
+
+pub const DEFAULT_ANSWERS: &str = "answers.toml";
+
+/// Known-good answers, keyed by day, input and part:
+///
+/// ```toml
+/// [day01.input]
+/// pt1 = 54331
+///
+/// [day01.example]
+/// pt1 = 142
+/// pt2 = 281
+/// ```
+///
+/// Answers that do not fit in 64 bits can be written as strings. Years other
+/// than the default one nest their days under the year, as in
+/// `[2024.day01.input]`.
+pub struct Expected {
+    table: toml::Table,
+}
+
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+    Error(String),
+}
+
+impl Expected {
+    pub fn load(path: &Path) -> Result<Expected, Error> {
+        let contents = std::fs::read_to_string(path)?;
+        let table = contents
+            .parse::<toml::Table>()
+            .map_err(|e| Error::Config(format!("{}: {}", path.display(), e.message())))?;
+        Ok(Expected { table })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8, example: bool) -> Option<String> {
+        let input = if example { "example" } else { "input" };
+        let table = if year == DEFAULT_YEAR {
+            &self.table
+        } else {
+            self.table.get(&year.to_string())?.as_table()?
+        };
+        let value = table
+            .get(&format!("day{:02}", day))?
+            .get(input)?
+            .get(format!("pt{}", part))?;
+        match value {
+            toml::Value::String(s) => Some(s.clone()),
+            v => Some(v.to_string()),
+        }
+    }
+
+    pub fn verdict(&self, run: &Run, example: bool) -> Verdict {
+        let actual = match &run.result {
+            Ok(answer) => answer,
+            Err(e) => return Verdict::Error(e.to_string()),
+        };
+        match self.get(run.year, run.day, run.part, example) {
+            None => Verdict::Missing,
+            Some(expected) if expected == actual.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected,
+                actual: actual.to_string(),
+            },
+        }
+    }
+}
+
+fn difference(expected: &str, actual: &Answer) -> Option<i128> {
+    let expected = expected.parse::<i128>().ok()?;
+    match actual {
+        Answer::Int(x) => Some(*x as i128 - expected),
+        Answer::Big(x) => x.checked_sub(expected),
+        Answer::Text(_) => None,
+    }
+}
+
+/// Prints a pass/fail/missing line per run and returns whether nothing failed.
+/// Missing answers are reported but do not count as failures. With `--quiet`
+/// only the failures and the totals are printed.
+pub fn print_report(runs: &[Run], expected: &Expected, example: bool) -> bool {
+    let quiet = !log::enabled(Level::Normal);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for run in runs {
+        let label = format!("Day {:>2} pt{}", run.day, run.part);
+        match expected.verdict(run, example) {
+            Verdict::Pass => {
+                passed += 1;
+                if !quiet {
+                    println!("{}: pass", label);
+                }
+            }
+            Verdict::Missing => {
+                missing += 1;
+                if !quiet {
+                    println!("{}: missing", label);
+                }
+            }
+            Verdict::Error(e) => {
+                failed += 1;
+                println!("{}: FAIL\n    error:    {}", label, e);
+            }
+            Verdict::Fail { expected, actual } => {
+                failed += 1;
+                println!("{}: FAIL\n    expected: {}\n    actual:   {}", label, expected, actual);
+                if let Some(Some(delta)) = run.result.as_ref().ok().map(|a| difference(&expected, a)) {
+                    println!("    delta:    {:+}", delta);
+                }
+            }
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    failed == 0
+}
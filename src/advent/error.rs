@@ -0,0 +1,181 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// Errors surfaced by the runner and by every solver.
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    /// No input file was found where convention expects one.
+    MissingInput(PathBuf),
+    /// A file that would be generated is already there.
+    Exists(PathBuf),
+    /// A parser rejected the input at the given 1-based position.
+    Parse {
+        line: usize,
+        column: usize,
+        snippet: String,
+        reason: String,
+    },
+    /// Parsing succeeded but left input behind.
+    Remainder {
+        line: usize,
+        column: usize,
+        snippet: String,
+    },
+    /// The input parsed but breaks an assumption the solver relies on.
+    Invalid(String),
+    /// The input is well formed but the approach taken cannot solve it.
+    Unsolvable(String),
+    /// A supporting file, such as the answers file, could not be understood.
+    Config(String),
+    NoSuchYear(u16),
+    NoSuchExercise(u8),
+    NoSuchPart(u8, u8),
+}
+
+const SNIPPET_LEN: usize = 24;
+
+/// Returns the 1-based line and column of `rest` within `input`, along with the
+/// text from that position to the end of its line. Falls back to 1:1 if `rest`
+/// is not the tail of `input`, as when the parser was handed a trimmed copy.
+fn locate(input: &str, rest: &str) -> (usize, usize, String) {
+    let offset = input.len().saturating_sub(rest.len());
+    let consumed = if input.ends_with(rest) && input.is_char_boundary(offset) {
+        &input[..offset]
+    } else {
+        ""
+    };
+    let line = consumed.matches('\n').count() + 1;
+    let column = consumed.len() - consumed.rfind('\n').map_or(0, |i| i + 1) + 1;
+    let snippet = rest
+        .lines()
+        .next()
+        .unwrap_or("")
+        .chars()
+        .take(SNIPPET_LEN)
+        .collect();
+    (line, column, snippet)
+}
+
+impl Error {
+    pub fn parse(input: &str, rest: &str, reason: impl Display) -> Error {
+        let (line, column, snippet) = locate(input, rest);
+        Error::Parse {
+            line,
+            column,
+            snippet,
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn remainder(input: &str, rest: &str) -> Error {
+        let (line, column, snippet) = locate(input, rest);
+        Error::Remainder {
+            line,
+            column,
+            snippet,
+        }
+    }
+
+    /// Converts a nom failure into a located parse error. `input` must be the
+    /// string the failing parser was originally handed.
+    pub fn nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Error {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Error::parse(input, e.input, format!("{:?}", e.code))
+            }
+            nom::Err::Incomplete(_) => Error::parse(input, "", "unexpected end of input"),
+        }
+    }
+
+    /// Rebases the position of a parse error onto `line` of the puzzle input,
+    /// for parsers that only ever see a single line.
+    pub fn on_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse {
+                column,
+                snippet,
+                reason,
+                ..
+            } => Error::Parse {
+                line,
+                column,
+                snippet,
+                reason,
+            },
+            Error::Remainder {
+                column, snippet, ..
+            } => Error::Remainder {
+                line,
+                column,
+                snippet,
+            },
+            e => e,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::MissingInput(path) => write!(f, "No input file at {}", path.display()),
+            Error::Exists(path) => write!(f, "{} already exists", path.display()),
+            Error::Parse {
+                line,
+                column,
+                snippet,
+                reason,
+            } => write!(
+                f,
+                "Parse error at {}:{} ({}) near {:?}",
+                line, column, reason, snippet
+            ),
+            Error::Remainder {
+                line,
+                column,
+                snippet,
+            } => write!(
+                f,
+                "Input not fully consumed, remainder at {}:{} starts with {:?}",
+                line, column, snippet
+            ),
+            Error::Invalid(s) => write!(f, "Invalid input: {}", s),
+            Error::Unsolvable(s) => write!(f, "Unsolvable input: {}", s),
+            Error::Config(s) => write!(f, "Bad configuration: {}", s),
+            Error::NoSuchYear(year) => write!(f, "No exercises for year {}", year),
+            Error::NoSuchExercise(exercise) => write!(f, "No such exercise found: {}", exercise),
+            Error::NoSuchPart(exercise, part) => {
+                write!(f, "No such exercise found: {}, pt{}", exercise, part)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_within_and_outside_input() {
+        assert_eq!(locate("ab\ncd", "d"), (2, 2, "d".to_owned()));
+        // not the tail of the input, as after trimming, or not on a boundary
+        assert_eq!(locate("ab\ncd\n", "cd"), (1, 1, "cd".to_owned()));
+        assert_eq!(locate("é", "x"), (1, 1, "x".to_owned()));
+    }
+}
@@ -0,0 +1,66 @@
+use std::env;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use super::{Error, DEFAULT_YEAR};
+
+/// Environment variable overriding the directory inputs are looked up in.
+pub const INPUTS_ENV: &str = "ADVENT_INPUTS";
+pub const DEFAULT_INPUTS: &str = "inputs";
+/// The datafile name that stands for standard input.
+pub const STDIN: &str = "-";
+
+/// The directory holding the `dayNN.txt` inputs: `$ADVENT_INPUTS` when set,
+/// `inputs/` otherwise.
+pub fn inputs_dir() -> PathBuf {
+    match env::var_os(INPUTS_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(DEFAULT_INPUTS),
+    }
+}
+
+/// The directory within `dir` holding the inputs for `year`: `dir` itself for
+/// the default year, `dir/YYYY` for any other.
+pub fn year_dir(dir: &Path, year: u16) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        dir.to_owned()
+    } else {
+        dir.join(year.to_string())
+    }
+}
+
+/// Path of the input for `day` within `dir`, by convention `dayNN.txt`, or
+/// `dayNN.example.txt` for the example from the puzzle description. Days whose
+/// second part comes with its own example keep it in `dayNN.example.pt2.txt`.
+pub fn input_path(dir: &Path, day: u8, part: u8, example: bool) -> PathBuf {
+    if example {
+        let own = dir.join(format!("day{:02}.example.pt{}.txt", day, part));
+        if own.is_file() {
+            return own;
+        }
+        dir.join(format!("day{:02}.example.txt", day))
+    } else {
+        dir.join(format!("day{:02}.txt", day))
+    }
+}
+
+/// Like `input_path`, but fails with the expected location if there is no
+/// such file.
+pub fn find_input(dir: &Path, day: u8, part: u8, example: bool) -> Result<String, Error> {
+    let path = input_path(dir, day, part, example);
+    if !path.is_file() {
+        return Err(Error::MissingInput(path));
+    }
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Reads the whole puzzle input from `data`, a path or `-` for stdin.
+pub fn read_input(data: &str) -> Result<String, Error> {
+    let mut input = String::new();
+    if data == STDIN {
+        io::stdin().lock().read_to_string(&mut input)?;
+    } else {
+        input = std::fs::read_to_string(data)?;
+    }
+    Ok(input)
+}
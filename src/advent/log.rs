@@ -0,0 +1,69 @@
+//! Diagnostics from the solvers. They go to stderr, so the answers and timings
+//! on stdout stay clean, and only when the verbosity asks for them.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much to report, from `--quiet` up to `-vv`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Nothing but the answers and any errors.
+    Quiet,
+    /// The default: answers, timings and the reasons runs failed.
+    Normal,
+    /// A summary of how each solver got its answer.
+    Verbose,
+    /// Every step along the way. Slow on real inputs.
+    Trace,
+}
+
+impl Level {
+    /// The level chosen by `--quiet` or by the number of `-v` flags.
+    pub fn from_flags(quiet: bool, verbose: u8) -> Level {
+        match (quiet, verbose) {
+            (true, _) => Level::Quiet,
+            (false, 0) => Level::Normal,
+            (false, 1) => Level::Verbose,
+            (false, _) => Level::Trace,
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Normal as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Normal,
+        2 => Level::Verbose,
+        _ => Level::Trace,
+    }
+}
+
+/// Whether output meant for `level` should be shown.
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+/// Reports a summary line, shown with `-v`.
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::advent::log::enabled($crate::advent::log::Level::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Reports a single step, shown with `-vv`.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::advent::log::enabled($crate::advent::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use {trace, verbose};
@@ -1,82 +1,41 @@
 mod util;
-mod d01;
-mod d02;
-mod d03;
-mod d04;
-mod d05;
-mod d06;
-mod d07;
-mod d08;
-mod d09;
-mod d10;
-mod d11;
-mod d12;
-mod d13;
-mod d14;
-mod d15;
-mod d16;
-mod d17;
-mod d18;
-mod d19;
-mod d20;
-mod d21;
-mod d22;
-mod d23;
-mod d24;
-mod d25;
+mod answer;
+mod error;
+mod solution;
+pub mod check;
+pub mod input;
+pub mod log;
+pub mod runner;
+pub mod scaffold;
+pub mod timing;
+mod y2023;
 
-pub fn run (exercise: u8, part: u8, file: String) -> Result<(), Box<dyn std::error::Error>> {
-    match (exercise, part) {
-        (1,1) => { d01::pt1(file) }
-        (1,2) => { d01::pt2(file) }
-        (2,1) => { d02::pt1(file) }
-        (2,2) => { d02::pt2(file) }
-        (3,1) => { d03::pt1(file) }
-        (3,2) => { d03::pt2(file) }
-        (4,1) => { d04::pt1(file) }
-        (4,2) => { d04::pt2(file) }
-        (5,1) => { d05::pt1(file) }
-        (5,2) => { d05::pt2(file) }
-        (6,1) => { d06::pt1(file) }
-        (6,2) => { d06::pt2(file) }
-        (7,1) => { d07::pt1(file) }
-        (7,2) => { d07::pt2(file) }
-        (8,1) => { d08::pt1(file) }
-        (8,2) => { d08::pt2(file) }
-        (9,1) => { d09::pt1(file) }
-        (9,2) => { d09::pt2(file) }
-        (10,1) => { d10::pt1(file) }
-        (10,2) => { d10::pt2(file) }
-        (11,1) => { d11::pt1(file) }
-        (11,2) => { d11::pt2(file) }
-        (12,1) => { d12::pt1(file) }
-        (12,2) => { d12::pt2(file) }
-        (13,1) => { d13::pt1(file) }
-        (13,2) => { d13::pt2(file) }
-        (14,1) => { d14::pt1(file) }
-        (14,2) => { d14::pt2(file) }
-        (15,1) => { d15::pt1(file) }
-        (15,2) => { d15::pt2(file) }
-        (16,1) => { d16::pt1(file) }
-        (16,2) => { d16::pt2(file) }
-        (17,1) => { d17::pt1(file) }
-        (17,2) => { d17::pt2(file) }
-        (18,1) => { d18::pt1(file) }
-        (18,2) => { d18::pt2(file) }
-        (19,1) => { d19::pt1(file) }
-        (19,2) => { d19::pt2(file) }
-        (20,1) => { d20::pt1(file) }
-        (20,2) => { d20::pt2(file) }
-        (21,1) => { d21::pt1(file) }
-        (21,2) => { d21::pt2(file) }
-        (22,1) => { d22::pt1(file) }
-        (22,2) => { d22::pt2(file) }
-        (23,1) => { d23::pt1(file) }
-        (23,2) => { d23::pt2(file) }
-        (24,1) => { d24::pt1(file) }
-        (24,2) => { d24::pt2(file) }
-        (25,1) => { d25::pt1(file) }
-        (25,2) => { d25::pt2(file) }
-        _ => { print!("No such exercise found: {}, pt{}", exercise, part); Ok(()) }
+pub use self::answer::Answer;
+pub use self::error::Error;
+pub use self::solution::Solution;
+pub use self::util::pool::set_threads;
+
+/// The year run when none is given.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Every year's solutions, in order.
+pub static YEARS: &[&[&dyn Solution]] = &[
+    y2023::SOLUTIONS,
+];
+
+pub fn solutions(year: u16) -> impl Iterator<Item = &'static dyn Solution> {
+    YEARS.iter().flat_map(|s| s.iter().copied()).filter(move |s| s.year() == year)
+}
+
+pub fn find(year: u16, exercise: u8) -> Result<&'static dyn Solution, Error> {
+    if solutions(year).next().is_none() {
+        return Err(Error::NoSuchYear(year));
     }
+    solutions(year)
+        .find(|s| s.day() == exercise)
+        .ok_or(Error::NoSuchExercise(exercise))
+}
+
+pub fn run(year: u16, exercise: u8, part: u8, input: &str) -> Result<Answer, Error> {
+    find(year, exercise)?.run(part, input)
 }
@@ -0,0 +1,168 @@
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::time::Duration;
+
+use super::log::{self, Level};
+use super::util::pool;
+use super::{input, timing, Answer, Error, Solution};
+
+/// Outcome of running a single part.
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Where the input was read from, `-` for stdin. Unknown if the input
+    /// could not be read.
+    pub path: Option<String>,
+    pub result: Result<Answer, Error>,
+    pub elapsed: Duration,
+    /// The part of `elapsed` spent parsing, if the solver marked it.
+    pub parse: Option<Duration>,
+}
+
+impl Run {
+    pub fn new(solution: &dyn Solution, part: u8, path: &str, input: &str) -> Run {
+        let (result, phases) = timing::phases(|| solution.run(part, input));
+        Run {
+            year: solution.year(),
+            day: solution.day(),
+            part,
+            path: Some(path.to_owned()),
+            result,
+            elapsed: phases.total(),
+            parse: phases.parse,
+        }
+    }
+
+    /// A run that never got as far as calling the solver.
+    pub fn failed(year: u16, day: u8, part: u8, error: Error) -> Run {
+        Run {
+            year,
+            day,
+            part,
+            path: None,
+            result: Err(error),
+            elapsed: Duration::ZERO,
+            parse: None,
+        }
+    }
+
+    /// The run as a JSON object, holding either its answer or its error.
+    pub fn to_json(&self) -> serde_json::Value {
+        let (answer, error) = match &self.result {
+            Ok(answer) => (answer.into(), serde_json::Value::Null),
+            Err(e) => (serde_json::Value::Null, e.to_string().into()),
+        };
+        serde_json::json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "input": self.path,
+            "answer": answer,
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1000.0,
+            "parse_ms": self.parse.map(|d| d.as_secs_f64() * 1000.0),
+            "error": error,
+        })
+    }
+}
+
+/// Parses a day selection such as `5`, `5..12`, `5..=12`, `5..` or `..=12`.
+pub fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let bound = |b: &str, default: u8| -> Result<u8, String> {
+        if b.is_empty() {
+            Ok(default)
+        } else {
+            b.parse::<u8>().map_err(|e| format!("{}: {}", b, e))
+        }
+    };
+    if let Some((start, end)) = s.split_once("..=") {
+        Ok(bound(start, u8::MIN)?..=bound(end, u8::MAX)?)
+    } else if let Some((start, end)) = s.split_once("..") {
+        if end.is_empty() {
+            return Ok(bound(start, u8::MIN)?..=u8::MAX);
+        }
+        let end = bound(end, u8::MAX)?;
+        if end == 0 {
+            return Err(format!("{}: empty range", s));
+        }
+        Ok(bound(start, u8::MIN)?..=end - 1)
+    } else {
+        let day = bound(s, 0)?;
+        Ok(day..=day)
+    }
+}
+
+/// Runs every registered part of `year` within `days` against its input in
+/// `dir`, one after another so that each is timed on its own. With `parallel`
+/// the parts run concurrently on the worker pool instead, and their timings
+/// include any contention for cores.
+pub fn run_all(
+    year: u16,
+    days: &RangeInclusive<u8>,
+    parts: &[u8],
+    dir: &Path,
+    example: bool,
+    parallel: bool,
+) -> Vec<Run> {
+    let mut jobs = Vec::new();
+    for solution in super::solutions(year).filter(|s| days.contains(&s.day())) {
+        for &part in parts {
+            jobs.push((solution, part));
+        }
+    }
+    let run = |&(solution, part): &(&dyn Solution, u8)| {
+        let data = input::find_input(dir, solution.day(), part, example)
+            .and_then(|path| Ok((input::read_input(&path)?, path)));
+        match data {
+            Ok((input, path)) => Run::new(solution, part, &path, &input),
+            Err(e) => Run::failed(solution.year(), solution.day(), part, e),
+        }
+    };
+    if parallel {
+        pool::map(&jobs, run)
+    } else {
+        jobs.iter().map(run).collect()
+    }
+}
+
+/// Prints the answer and time of every run, then why any failed. With
+/// `--quiet` only the day, part and answer are printed.
+pub fn print_table(runs: &[Run]) {
+    if !log::enabled(Level::Normal) {
+        for run in runs {
+            match &run.result {
+                Ok(answer) => println!("{} {} {}", run.day, run.part, answer),
+                Err(e) => eprintln!("Day {} pt{}: {}", run.day, run.part, e),
+            }
+        }
+        return;
+    }
+    println!("{:>3} {:>4}  {:<40} {:>12}", "Day", "Part", "Answer", "Time");
+    for run in runs {
+        let answer = match &run.result {
+            Ok(answer) => answer.to_string(),
+            Err(_) => "FAILED".to_owned(),
+        };
+        println!(
+            "{:>3} {:>4}  {:<40} {:>12}",
+            run.day,
+            run.part,
+            answer,
+            format!("{:.2?}", run.elapsed)
+        );
+    }
+    let total: Duration = runs.iter().map(|r| r.elapsed).sum();
+    println!("{:>3} {:>4}  {:<40} {:>12}", "", "", "Total", format!("{:.2?}", total));
+    for run in runs {
+        if let Err(e) = &run.result {
+            eprintln!("Day {} pt{}: {}", run.day, run.part, e);
+        }
+    }
+}
+
+/// Prints every run as a JSON object on a line of its own.
+pub fn print_json(runs: &[Run]) {
+    for run in runs {
+        println!("{}", run.to_json());
+    }
+}
@@ -0,0 +1,299 @@
+//! Generates the module for a new day and registers it, as `new-day NN` does.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::input::{self, DEFAULT_INPUTS};
+use super::Error;
+
+const DAY_TEMPLATE: &str = r#"use nom::character::complete::not_line_ending;
+
+use super::{Answer, Error, Solution};
+use super::log::{trace, verbose};
+use super::timing;
+use super::util::parse::{lines, parse_complete};
+
+fn parse_input(input: &str) -> Result<Vec<&str>, Error> {
+    parse_complete(input, lines(not_line_ending))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let rows = parse_input(input)?;
+    timing::parsed();
+    verbose!("{} rows", rows.len());
+    Err(Error::Unsolvable("pt1 is not solved yet".to_owned()))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let rows = parse_input(input)?;
+    timing::parsed();
+    trace!("{:?}", rows);
+    Err(Error::Unsolvable("pt2 is not solved yet".to_owned()))
+}
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    fn year(&self) -> u16 {
+        {YEAR}
+    }
+
+    fn day(&self) -> u8 {
+        {N}
+    }
+
+    fn title(&self) -> &'static str {
+        "{TITLE}"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"//! The puzzles of Advent of Code {YEAR}.
+
+use super::{log, timing, util, Answer, Error, Solution};
+
+mod d{NN};
+
+pub(super) static SOLUTIONS: &[&dyn Solution] = &[
+    &d{NN}::Day{NN},
+];
+"#;
+
+const EXAMPLES_TEMPLATE: &str = r#"
+mod y{YEAR} {
+    use super::*;
+
+    examples! {
+        {YEAR};
+        {ENTRY}
+    }
+}
+"#;
+
+/// The example test of a new day. It runs the solver on the example and fails
+/// until the placeholders are replaced with the example's answers.
+const EXAMPLE_ENTRY: &str =
+    r#"day{NN}: {N} => Some("TODO: the example's pt1 answer"), Some("TODO: the example's pt2 answer");"#;
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{ENTRY}", EXAMPLE_ENTRY)
+        .replace("{YEAR}", &year.to_string())
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string())
+}
+
+/// Inserts `line` into the run of `lines` that `key` numbers, after the last
+/// one numbered below `at`, keeping the run in order.
+fn insert_sorted(
+    path: &Path,
+    lines: &mut Vec<String>,
+    at: u16,
+    key: impl Fn(&str) -> Option<u16>,
+    line: String,
+) -> Result<(), Error> {
+    let keyed: Vec<(usize, u16)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect();
+    if keyed.iter().any(|&(_, k)| k == at) {
+        return Err(Error::Exists(path.to_owned()));
+    }
+    let index = match keyed.iter().rev().find(|&&(_, k)| k < at) {
+        Some(&(i, _)) => i + 1,
+        None => match keyed.first() {
+            Some(&(i, _)) => i,
+            None => {
+                return Err(Error::Config(format!(
+                    "{}: found nowhere to register {}",
+                    path.display(),
+                    line.trim()
+                )))
+            }
+        },
+    };
+    lines.insert(index, line);
+    Ok(())
+}
+
+fn split(text: &str) -> Vec<String> {
+    text.lines().map(str::to_owned).collect()
+}
+
+fn join(lines: &[String]) -> String {
+    lines.join("\n") + "\n"
+}
+
+fn year_mod_key(line: &str) -> Option<u16> {
+    line.strip_prefix("mod y")?.strip_suffix(';')?.parse().ok()
+}
+
+fn years_key(line: &str) -> Option<u16> {
+    line.trim_start().strip_prefix('y')?.strip_suffix("::SOLUTIONS,")?.parse().ok()
+}
+
+fn mod_key(line: &str) -> Option<u16> {
+    line.strip_prefix("mod d")?.strip_suffix(';')?.parse().ok()
+}
+
+fn solutions_key(line: &str) -> Option<u16> {
+    line.trim_start().strip_prefix("&d")?.get(..2)?.parse().ok()
+}
+
+fn examples_key(line: &str) -> Option<u16> {
+    let (name, _) = line.trim_start().split_once(": ")?;
+    name.strip_prefix("day")?.parse().ok()
+}
+
+/// Registers `day` of `year` with the example tests, in that year's module of
+/// `examples.rs`, which is added if the year has none yet.
+fn register_example(path: &Path, text: &str, year: u16, day: u8) -> Result<String, Error> {
+    let mut lines = split(text);
+    let header = format!("mod y{} {{", year);
+    let start = match lines.iter().position(|l| *l == header) {
+        Some(start) => start,
+        None => return Ok(text.trim_end().to_owned() + "\n" + &fill(EXAMPLES_TEMPLATE, year, day)),
+    };
+    let end = lines[start..]
+        .iter()
+        .position(|l| l == "}")
+        .map_or(lines.len(), |i| start + i);
+    let mut block = lines[start..end].to_vec();
+    let line = "        ".to_owned() + &fill(EXAMPLE_ENTRY, year, day);
+    insert_sorted(path, &mut block, day.into(), examples_key, line)?;
+    lines.splice(start..end, block);
+    Ok(join(&lines))
+}
+
+/// Writes `src/advent/yYYYY/dNN.rs` and an empty `dayNN.example.txt` in the
+/// year's inputs under `root`, and registers the day with its year's module
+/// and with the example tests. Its example test fails until the example and
+/// both of its answers are filled in. The year's module is generated too if
+/// this is its first day. Nothing is written if the day already exists.
+/// Returns the files created or changed.
+pub fn new_day(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::NoSuchExercise(day));
+    }
+    let advent = root.join("src/advent");
+    let module = advent.join(format!("y{}/d{:02}.rs", year, day));
+    if module.exists() {
+        return Err(Error::Exists(module));
+    }
+    let mut writes = Vec::new();
+
+    let year_rs = advent.join(format!("y{}/mod.rs", year));
+    if year_rs.exists() {
+        let mut lines = split(&fs::read_to_string(&year_rs)?);
+        let line = format!("mod d{:02};", day);
+        insert_sorted(&year_rs, &mut lines, day.into(), mod_key, line)?;
+        let line = format!("    &d{:02}::Day{:02},", day, day);
+        insert_sorted(&year_rs, &mut lines, day.into(), solutions_key, line)?;
+        writes.push((year_rs, join(&lines)));
+    } else {
+        let mod_rs = advent.join("mod.rs");
+        let mut lines = split(&fs::read_to_string(&mod_rs)?);
+        let line = format!("mod y{};", year);
+        insert_sorted(&mod_rs, &mut lines, year, year_mod_key, line)?;
+        let line = format!("    y{}::SOLUTIONS,", year);
+        insert_sorted(&mod_rs, &mut lines, year, years_key, line)?;
+        writes.push((mod_rs, join(&lines)));
+        writes.push((year_rs, fill(YEAR_TEMPLATE, year, day)));
+    }
+
+    let examples_rs = root.join("tests/examples.rs");
+    let examples = fs::read_to_string(&examples_rs)?;
+    writes.push((examples_rs.clone(), register_example(&examples_rs, &examples, year, day)?));
+
+    let source = fill(DAY_TEMPLATE, year, day)
+        .replace("{TITLE}", &title.replace('\\', "\\\\").replace('"', "\\\""));
+    writes.push((module, source));
+    let example = input::year_dir(&root.join(DEFAULT_INPUTS), year)
+        .join(format!("day{:02}.example.txt", day));
+    if !example.exists() {
+        writes.push((example, String::new()));
+    }
+
+    let mut touched = Vec::new();
+    for (path, contents) in writes {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, contents)?;
+        touched.push(path);
+    }
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "mod util;\nmod y2023;\n\npub static YEARS: &[&[&dyn Solution]] = &[\n    y2023::SOLUTIONS,\n];\n";
+    const YEAR_RS: &str = "mod d01;\nmod d03;\n\npub(super) static SOLUTIONS: &[&dyn Solution] = &[\n    &d01::Day01,\n    &d03::Day03,\n];\n";
+    const EXAMPLES_RS: &str = "mod y2023 {\n    use super::*;\n\n    examples! {\n        2023;\n        day01: 1 => Some(142), Some(281);\n        // a comment\n        day03: 3 => Some(4361), NONE;\n    }\n}\n";
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    /// A scratch directory, removed again however the test ends.
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let dir = TempDir(std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id())));
+        let root = &dir.0;
+        fs::create_dir_all(root.join("src/advent/y2023")).unwrap();
+        fs::create_dir_all(root.join("tests")).unwrap();
+        fs::write(root.join("src/advent/mod.rs"), MOD_RS).unwrap();
+        fs::write(root.join("src/advent/y2023/mod.rs"), YEAR_RS).unwrap();
+        fs::write(root.join("tests/examples.rs"), EXAMPLES_RS).unwrap();
+
+        let touched = new_day(root, 2023, 2, "Cube \"Conundrum\"").unwrap();
+        assert_eq!(touched.len(), 4);
+        let module = read(root, "src/advent/y2023/d02.rs");
+        assert!(module.contains("pub struct Day02;"));
+        assert!(module.contains("use super::log::{trace, verbose};"));
+        assert!(module.contains("        2023\n"));
+        assert!(module.contains("        2\n"));
+        assert!(module.contains(r#""Cube \"Conundrum\"""#));
+        let registry = read(root, "src/advent/y2023/mod.rs");
+        assert!(registry.contains("mod d01;\nmod d02;\nmod d03;\n"));
+        assert!(registry.contains("    &d01::Day01,\n    &d02::Day02,\n    &d03::Day03,\n"));
+        let examples = read(root, "tests/examples.rs");
+        assert!(examples.contains("281);\n        day02: 2 => Some(\"TODO: the example's pt1 answer\"), Some(\"TODO: the example's pt2 answer\");\n        // a comment\n"));
+        assert!(root.join("inputs/day02.example.txt").is_file());
+
+        new_day(root, 2015, 7, "").unwrap();
+        let years = read(root, "src/advent/mod.rs");
+        assert!(years.contains("mod util;\nmod y2015;\nmod y2023;\n"));
+        assert!(years.contains("    y2015::SOLUTIONS,\n    y2023::SOLUTIONS,\n"));
+        let registry = read(root, "src/advent/y2015/mod.rs");
+        assert!(registry.contains("use super::{log, timing, util, Answer, Error, Solution};\n"));
+        assert!(registry.contains("    &d07::Day07,\n"));
+        let examples = read(root, "tests/examples.rs");
+        assert!(examples.ends_with("mod y2015 {\n    use super::*;\n\n    examples! {\n        2015;\n        day07: 7 => Some(\"TODO: the example's pt1 answer\"), Some(\"TODO: the example's pt2 answer\");\n    }\n}\n"));
+        assert!(root.join("inputs/2015/day07.example.txt").is_file());
+
+        assert!(matches!(new_day(root, 2023, 2, ""), Err(Error::Exists(_))));
+        assert!(matches!(new_day(root, 2023, 26, ""), Err(Error::NoSuchExercise(26))));
+    }
+}
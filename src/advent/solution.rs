@@ -0,0 +1,29 @@
+use std::io::BufRead;
+
+use super::{Answer, Error};
+
+/// A single day of the calendar. Each `yYYYY/dNN` module exposes a unit struct
+/// implementing this, its year's `SOLUTIONS` lists them all, and
+/// `advent::YEARS` lists the years.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn pt1(&self, input: &str) -> Result<Answer, Error>;
+    fn pt2(&self, input: &str) -> Result<Answer, Error>;
+
+    fn run(&self, part: u8, input: &str) -> Result<Answer, Error> {
+        match part {
+            1 => self.pt1(input),
+            2 => self.pt2(input),
+            _ => Err(Error::NoSuchPart(self.day(), part)),
+        }
+    }
+
+    /// Like `run`, reading the whole puzzle input from `reader` first.
+    fn run_reader(&self, part: u8, reader: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        self.run(part, &input)
+    }
+}
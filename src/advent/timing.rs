@@ -0,0 +1,215 @@
+//! Timing of solver runs, split into a parse and a solve phase, and the
+//! statistics reported by `--bench`.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use super::{Answer, Error, Solution};
+
+thread_local! {
+    static PARSED: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Marks the end of the running solver's parse phase. Solvers that parse as
+/// they go never call it, and their whole run counts as solving.
+pub fn parsed() {
+    PARSED.with(|p| {
+        if p.get().is_none() {
+            p.set(Some(Instant::now()));
+        }
+    });
+}
+
+/// How long a run spent in each phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Phases {
+    /// `None` if the solver never marked the end of parsing.
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+impl Phases {
+    pub fn total(&self) -> Duration {
+        self.parse.unwrap_or_default() + self.solve
+    }
+}
+
+/// Runs `f`, timing the phases on either side of its call to `parsed`.
+pub fn phases<T>(f: impl FnOnce() -> T) -> (T, Phases) {
+    PARSED.with(|p| p.set(None));
+    let start = Instant::now();
+    let result = f();
+    let end = Instant::now();
+    let phases = match PARSED.with(|p| p.take()) {
+        Some(parsed) => Phases {
+            parse: Some(parsed - start),
+            solve: end - parsed,
+        },
+        None => Phases {
+            parse: None,
+            solve: end - start,
+        },
+    };
+    (result, phases)
+}
+
+/// Summary statistics of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The population standard deviation.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn of(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        serde_json::json!({
+            "min_ms": ms(self.min),
+            "median_ms": ms(self.median),
+            "mean_ms": ms(self.mean),
+            "stddev_ms": ms(self.stddev),
+        })
+    }
+}
+
+/// The outcome of benchmarking a single part with `--bench`.
+pub struct Bench {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub path: String,
+    pub answer: Answer,
+    pub runs: usize,
+    /// `None` if the solver does not mark the end of parsing.
+    pub parse: Option<Stats>,
+    pub solve: Stats,
+    pub total: Stats,
+}
+
+/// Runs `part` on `input` `warmup` times untimed and then `runs` times timed,
+/// failing on the first error. `runs` must not be zero.
+pub fn bench(
+    solution: &dyn Solution,
+    part: u8,
+    path: &str,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Bench, Error> {
+    for _ in 0..warmup {
+        solution.run(part, input)?;
+    }
+    let mut answer = None;
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let (result, phases) = self::phases(|| solution.run(part, input));
+        answer = Some(result?);
+        samples.push(phases);
+    }
+    let parse: Option<Vec<Duration>> = samples.iter().map(|p| p.parse).collect();
+    let solve: Vec<Duration> = samples.iter().map(|p| p.solve).collect();
+    let total: Vec<Duration> = samples.iter().map(Phases::total).collect();
+    Ok(Bench {
+        year: solution.year(),
+        day: solution.day(),
+        part,
+        path: path.to_owned(),
+        answer: answer.expect("at least one run"),
+        runs,
+        parse: parse.and_then(|p| Stats::of(&p)),
+        solve: Stats::of(&solve).expect("at least one run"),
+        total: Stats::of(&total).expect("at least one run"),
+    })
+}
+
+impl Bench {
+    pub fn print(&self) {
+        println!("Day {} pt{}: {} ({} runs)", self.day, self.part, self.answer, self.runs);
+        println!("{:<6} {:>12} {:>12} {:>12} {:>12}", "", "min", "median", "mean", "stddev");
+        let phases = [("parse", self.parse), ("solve", Some(self.solve)), ("total", Some(self.total))];
+        for (name, stats) in phases {
+            match stats {
+                Some(s) => println!(
+                    "{:<6} {:>12} {:>12} {:>12} {:>12}",
+                    name,
+                    format!("{:.2?}", s.min),
+                    format!("{:.2?}", s.median),
+                    format!("{:.2?}", s.mean),
+                    format!("{:.2?}", s.stddev)
+                ),
+                None => println!("{:<6} {:>12}", name, "-"),
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "input": self.path,
+            "answer": serde_json::Value::from(&self.answer),
+            "runs": self.runs,
+            "parse": self.parse.map(|s| s.to_json()),
+            "solve": self.solve.to_json(),
+            "total": self.total.to_json(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&m| Duration::from_millis(m)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let s = Stats::of(&ms(&[4, 2, 8, 6])).unwrap();
+        assert_eq!(s.min, Duration::from_millis(2));
+        assert_eq!(s.median, Duration::from_millis(5));
+        assert_eq!(s.mean, Duration::from_millis(5));
+        assert_eq!(s.stddev.as_micros(), 2236);
+        assert_eq!(Stats::of(&ms(&[3, 1, 2])).unwrap().median, Duration::from_millis(2));
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn phases_split_at_the_mark() {
+        let ((), marked) = phases(parsed);
+        assert!(marked.parse.is_some());
+        let ((), unmarked) = phases(|| ());
+        assert_eq!(unmarked.parse, None);
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The shape of a sequence `x0, f(x0), f(f(x0)), ...` over finitely many
+/// states: after the first `start` states, it repeats every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest iteration holding the same state as iteration `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Every state up to the point the sequence starts repeating itself.
+#[derive(Debug, Clone)]
+pub(crate) struct History<S> {
+    pub cycle: Cycle,
+    /// The first `start + period` states, all distinct.
+    pub states: Vec<S>,
+}
+
+impl<S> History<S> {
+    /// The state after `n` steps, however large `n` is.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+/// Detects the cycle by remembering every state seen, at the cost of keeping
+/// the whole history in memory.
+pub(crate) fn find<S: Clone + Eq + Hash>(start: S, mut f: impl FnMut(&S) -> S) -> History<S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        if let Some(&first) = seen.get(&state) {
+            let cycle = Cycle {
+                start: first,
+                period: states.len() - first,
+            };
+            return History { cycle, states };
+        }
+        let next = f(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+/// The state after `n` steps, simulating at most until the sequence repeats.
+pub(crate) fn nth<S: Clone + Eq + Hash>(start: S, mut f: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    while states.len() < n {
+        if let Some(&first) = seen.get(&state) {
+            let cycle = Cycle {
+                start: first,
+                period: states.len() - first,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        let next = f(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 1 2 3 4 5 6 7 3 4 5 6 7 ...
+    fn step(&x: &u32) -> u32 {
+        if x == 7 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    const CYCLE: Cycle = Cycle {
+        start: 3,
+        period: 5,
+    };
+
+    #[test]
+    fn finds_cycle() {
+        assert_eq!(find(0, step).cycle, CYCLE);
+        assert_eq!(find(3, step).cycle, Cycle { start: 0, period: 5 });
+    }
+
+    #[test]
+    fn jumps_ahead() {
+        let history = find(0, step);
+        assert_eq!(history.states.len(), 8);
+        assert_eq!(*history.nth(2), 2);
+        assert_eq!(*history.nth(8), 3);
+        assert_eq!(*history.nth(1_000_000_000), 3 + (1_000_000_000 - 3) % 5);
+        assert_eq!(nth(0, step, 2), 2);
+        assert_eq!(nth(0, step, 1_000_000_000), 3 + (1_000_000_000 - 3) % 5);
+    }
+}
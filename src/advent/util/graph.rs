@@ -0,0 +1,392 @@
+use std::borrow::Borrow;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num_traits::Zero;
+
+/// Nodes are numbered densely from 0, in the order they were first seen.
+pub(crate) type NodeId = usize;
+
+/// Hands out a `NodeId` per distinct name, so graphs can be stored in
+/// vectors rather than maps keyed by strings.
+#[derive(Debug, Clone)]
+pub(crate) struct Interner<K> {
+    ids: HashMap<K, NodeId>,
+    names: Vec<K>,
+}
+
+impl<K> Default for Interner<K> {
+    fn default() -> Self {
+        Interner {
+            ids: HashMap::new(),
+            names: Vec::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> Interner<K> {
+    pub fn new() -> Interner<K> {
+        Interner::default()
+    }
+
+    /// The id of `name`, assigning the next free one if it is new.
+    pub fn intern(&mut self, name: K) -> NodeId {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.clone(), id);
+        self.names.push(name);
+        id
+    }
+
+    pub fn id<Q>(&self, name: &Q) -> Option<NodeId>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &K {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+}
+
+/// Adjacency lists with a weight per edge; use `()` for unweighted graphs.
+/// Nodes are created as edges mention them, and each node's edges keep the
+/// order they were added in. An undirected edge is stored once from each end.
+#[derive(Debug, Clone)]
+pub(crate) struct Graph<W = ()> {
+    adjacency: Vec<Vec<(NodeId, W)>>,
+    directed: bool,
+}
+
+impl<W: Copy> Graph<W> {
+    pub fn directed() -> Graph<W> {
+        Graph {
+            adjacency: Vec::new(),
+            directed: true,
+        }
+    }
+
+    pub fn undirected() -> Graph<W> {
+        Graph {
+            adjacency: Vec::new(),
+            directed: false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Makes sure node `id` exists, even if no edge touches it.
+    pub fn add_node(&mut self, id: NodeId) {
+        if id >= self.adjacency.len() {
+            self.adjacency.resize_with(id + 1, Vec::new);
+        }
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.add_node(from.max(to));
+        self.adjacency[from].push((to, weight));
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight));
+        }
+    }
+
+    /// The edges leaving `id`, with their weights.
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.adjacency[id]
+    }
+
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.adjacency[id].iter().map(|&(to, _)| to)
+    }
+
+    /// Every node reachable from `start`, nearest first, with its distance in
+    /// edges.
+    #[allow(dead_code)]
+    pub fn bfs(&self, start: NodeId) -> Bfs<'_, W> {
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        Bfs {
+            graph: self,
+            queue: VecDeque::from([(start, 0)]),
+            seen,
+        }
+    }
+
+    /// Every node reachable from `start`, each before the nodes first
+    /// discovered through it.
+    #[allow(dead_code)]
+    pub fn dfs(&self, start: NodeId) -> Dfs<'_, W> {
+        Dfs {
+            graph: self,
+            stack: vec![start],
+            seen: vec![false; self.len()],
+        }
+    }
+
+    /// Groups the nodes into connected components, ignoring edge direction.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut parent: Vec<NodeId> = (0..self.len()).collect();
+        fn root(parent: &mut [NodeId], mut id: NodeId) -> NodeId {
+            while parent[id] != id {
+                parent[id] = parent[parent[id]];
+                id = parent[id];
+            }
+            id
+        }
+        for from in 0..self.len() {
+            for to in self.neighbors(from) {
+                let (a, b) = (root(&mut parent, from), root(&mut parent, to));
+                parent[a] = b;
+            }
+        }
+        let mut groups: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        let mut order = Vec::new();
+        for id in 0..self.len() {
+            let r = root(&mut parent, id);
+            groups.entry(r).or_insert_with(|| {
+                order.push(r);
+                Vec::new()
+            }).push(id);
+        }
+        order.into_iter().map(|r| groups.remove(&r).unwrap()).collect()
+    }
+
+    /// Orders the nodes of a directed graph so that every edge points forward,
+    /// or `None` if there is a cycle.
+    pub fn toposort(&self) -> Option<Vec<NodeId>> {
+        let mut incoming = vec![0; self.len()];
+        for to in (0..self.len()).flat_map(|id| self.neighbors(id)) {
+            incoming[to] += 1;
+        }
+        let mut ready: Vec<NodeId> = (0..self.len()).filter(|&id| incoming[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = ready.pop() {
+            order.push(id);
+            for to in self.neighbors(id) {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push(to);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+}
+
+impl<W: Copy + Ord + Zero + Add<Output = W>> Graph<W> {
+    /// The lightest set of edges whose removal splits an undirected graph in
+    /// two, by Stoer-Wagner. Returns the cut's total weight and the nodes on
+    /// one side of it, or `None` with fewer than two nodes.
+    pub fn min_cut(&self) -> Option<(W, Vec<NodeId>)> {
+        let n = self.len();
+        if n < 2 {
+            return None;
+        }
+        // parallel edges add up, loops never cross a cut
+        let mut adjacency: Vec<HashMap<NodeId, W>> = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .map(|(from, edges)| {
+                let mut summed = HashMap::new();
+                for &(to, weight) in edges.iter().filter(|&&(to, _)| to != from) {
+                    let w = summed.entry(to).or_insert_with(W::zero);
+                    *w = *w + weight;
+                }
+                summed
+            })
+            .collect();
+        // the original nodes merged into each remaining one
+        let mut merged: Vec<Vec<NodeId>> = (0..n).map(|id| vec![id]).collect();
+        let mut remaining: Vec<NodeId> = (0..n).collect();
+        let mut best: Option<(W, Vec<NodeId>)> = None;
+
+        while remaining.len() > 1 {
+            // add the most tightly connected node until none are left; the
+            // last one's connection is the cut separating it from the one
+            // before
+            let mut connection = vec![W::zero(); n];
+            let mut added = vec![false; n];
+            let mut heap = BinaryHeap::from([(W::zero(), remaining[0])]);
+            let (mut previous, mut last) = (remaining[0], remaining[0]);
+            let mut count = 0;
+            while let Some((weight, id)) = heap.pop() {
+                if added[id] || weight != connection[id] {
+                    continue;
+                }
+                added[id] = true;
+                count += 1;
+                (previous, last) = (last, id);
+                for (&to, &w) in &adjacency[id] {
+                    if !added[to] {
+                        connection[to] = connection[to] + w;
+                        heap.push((connection[to], to));
+                    }
+                }
+            }
+            if count < remaining.len() {
+                // already in pieces
+                let side = remaining.iter().filter(|&&id| added[id]);
+                return Some((W::zero(), side.flat_map(|&id| merged[id].clone()).collect()));
+            }
+            let better = match &best {
+                Some((weight, _)) => connection[last] < *weight,
+                None => true,
+            };
+            if better {
+                best = Some((connection[last], merged[last].clone()));
+            }
+
+            let edges: Vec<(NodeId, W)> = adjacency[last].drain().collect();
+            for (to, w) in edges {
+                adjacency[to].remove(&last);
+                if to != previous {
+                    let e = adjacency[previous].entry(to).or_insert_with(W::zero);
+                    *e = *e + w;
+                    let e = adjacency[to].entry(previous).or_insert_with(W::zero);
+                    *e = *e + w;
+                }
+            }
+            let nodes = std::mem::take(&mut merged[last]);
+            merged[previous].extend(nodes);
+            remaining.retain(|&id| id != last);
+        }
+        best
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) struct Bfs<'a, W> {
+    graph: &'a Graph<W>,
+    queue: VecDeque<(NodeId, usize)>,
+    seen: Vec<bool>,
+}
+
+impl<W: Copy> Iterator for Bfs<'_, W> {
+    type Item = (NodeId, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, depth) = self.queue.pop_front()?;
+        for to in self.graph.neighbors(id) {
+            if !self.seen[to] {
+                self.seen[to] = true;
+                self.queue.push_back((to, depth + 1));
+            }
+        }
+        Some((id, depth))
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) struct Dfs<'a, W> {
+    graph: &'a Graph<W>,
+    stack: Vec<NodeId>,
+    seen: Vec<bool>,
+}
+
+impl<W: Copy> Iterator for Dfs<'_, W> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(id) = self.stack.pop() {
+            if self.seen[id] {
+                continue;
+            }
+            self.seen[id] = true;
+            // reversed, so the first edge is explored first
+            let edges = self.graph.edges(id);
+            self.stack.extend(edges.iter().rev().map(|&(to, _)| to).filter(|&to| !self.seen[to]));
+            return Some(id);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(NodeId, NodeId)]) -> Graph {
+        let mut graph = Graph::directed();
+        for &(from, to) in edges {
+            graph.add_edge(from, to, ());
+        }
+        graph
+    }
+
+    #[test]
+    fn interner_reuses_ids() {
+        let mut names = Interner::new();
+        assert_eq!(names.intern("a"), 0);
+        assert_eq!(names.intern("b"), 1);
+        assert_eq!(names.intern("a"), 0);
+        assert_eq!(names.id("b"), Some(1));
+        assert_eq!(names.id("c"), None);
+        assert_eq!(*names.name(1), "b");
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn traversals() {
+        // 0 -> 1 -> 3, 0 -> 2 -> 3 -> 4, and 5 on its own
+        let mut graph = directed(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+        graph.add_node(5);
+        let bfs: Vec<_> = graph.bfs(0).collect();
+        assert_eq!(bfs, vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 3)]);
+        let dfs: Vec<_> = graph.dfs(0).collect();
+        assert_eq!(dfs, vec![0, 1, 3, 4, 2]);
+        assert_eq!(graph.bfs(3).count(), 2);
+    }
+
+    #[test]
+    fn components_ignore_direction() {
+        let mut graph = directed(&[(1, 0), (2, 3)]);
+        graph.add_node(4);
+        assert_eq!(graph.components(), vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn toposort_orders_or_finds_cycles() {
+        let graph = directed(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+        let order = graph.toposort().unwrap();
+        let position = |id| order.iter().position(|&x| x == id).unwrap();
+        for from in 0..graph.len() {
+            for to in graph.neighbors(from) {
+                assert!(position(from) < position(to));
+            }
+        }
+        assert_eq!(directed(&[(0, 1), (1, 2), (2, 0)]).toposort(), None);
+    }
+
+    #[test]
+    fn min_cut_splits_two_cliques() {
+        let mut graph = Graph::undirected();
+        for (a, b) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)] {
+            graph.add_edge(a, b, 1);
+            graph.add_edge(a + 4, b + 4, 1);
+        }
+        graph.add_edge(3, 4, 1);
+        graph.add_edge(2, 5, 1);
+        let (weight, mut side) = graph.min_cut().unwrap();
+        side.sort();
+        assert_eq!(weight, 2);
+        assert!(side == vec![0, 1, 2, 3] || side == vec![4, 5, 6, 7]);
+
+        let mut apart = Graph::undirected();
+        apart.add_edge(0, 1, 5);
+        apart.add_edge(2, 3, 5);
+        assert_eq!(apart.min_cut().map(|(w, s)| (w, s.len())), Some((0, 2)));
+        assert_eq!(Graph::<u32>::undirected().min_cut(), None);
+    }
+}
@@ -1,30 +1,415 @@
-#[allow(dead_code)]
-pub(crate) fn print_grid<T>(grid: &mut [&mut [T]], spacing: usize)
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::advent::log::{self, Level};
+use crate::advent::Error;
+
+/// A compass direction on a grid whose rows grow southward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The `(row, col)` offset of a single step.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+
+    /// Steps from `(row, col)` on an unbounded plane.
+    pub fn apply(self, (row, col): (isize, isize)) -> (isize, isize) {
+        let (dr, dc) = self.delta();
+        (row + dr, col + dc)
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+const DIAGONALS: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, 1), (1, -1)];
+
+/// An owned two-dimensional grid stored row-major, indexed either by
+/// `(row, col)` or by row to get a slice, so `grid[row][col]` also works.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from row-major `cells`. Returns `None` if `cells` does not
+    /// hold a whole number of rows of `width`.
+    #[allow(dead_code)]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Grid<T>> {
+        if width == 0 {
+            return None;
+        }
+        let height = cells.len() / width;
+        if width * height != cells.len() {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos).then(|| &self[pos])
+    }
+
+    #[allow(dead_code)]
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    #[allow(dead_code)]
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    #[allow(dead_code)]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every `(row, col)` in the grid, in storage order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// Every cell along with its `(row, col)`, in storage order.
+    pub fn indexed(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, in storage order, whose cell satisfies `pred`.
+    #[allow(dead_code)]
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let idx = self.cells.iter().position(pred)?;
+        Some((idx / self.width, idx % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per line of `input`, turning each character into a cell
+    /// through `cell`. A character `cell` returns `None` for is a parse error,
+    /// as is a line whose length differs from the first.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, Error> {
+        Grid::parse_with_markers(input, &[], cell).map(|(grid, _)| grid)
+    }
+
+    /// Like `parse`, also recording where each of the `markers` characters
+    /// was found. Markers are still handed to `cell` like any other character.
+    pub fn parse_with_markers(
+        input: &str,
+        markers: &[char],
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Grid<T>, Markers), Error> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut found = Markers::default();
+        let mut offset = 0;
+        for (row, line) in input.split_inclusive('\n').enumerate() {
+            let rest = &input[offset..];
+            offset += line.len();
+            let line = line.trim_end_matches(['\n', '\r']);
+            let mut len = 0;
+            for (idx, c) in line.char_indices() {
+                if markers.contains(&c) {
+                    found.0.entry(c).or_default().push((row, len));
+                }
+                let value = cell(c)
+                    .ok_or_else(|| Error::parse(input, &rest[idx..], format!("unexpected {:?}", c)))?;
+                cells.push(value);
+                len += 1;
+            }
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(Error::parse(
+                        input,
+                        rest,
+                        format!("line of {} cells in a grid {} wide", len, width),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        let width = width
+            .filter(|&width| width > 0)
+            .ok_or(Error::Invalid("Empty grid".to_owned()))?;
+        let height = cells.len() / width;
+        Ok((
+            Grid {
+                width,
+                height,
+                cells,
+            },
+            found,
+        ))
+    }
+}
+
+impl<T> Grid<T> {
+    /// `pos` moved by `(dr, dc)`, if that is still on the grid.
+    pub fn offset(&self, (row, col): (usize, usize), (dr, dc): (isize, isize)) -> Option<(usize, usize)> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The cell one step from `pos` towards `dir`, if there is one.
+    pub fn step(&self, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+        self.offset(pos, dir.delta())
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that lie on the grid.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `pos` that lie
+    /// on the grid.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(Direction::delta)
+            .chain(DIAGONALS)
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Maps a position on the infinite plane tiled with copies of this grid
+    /// back onto the grid.
+    pub fn wrap(&self, (row, col): (isize, isize)) -> (usize, usize) {
+        (
+            row.rem_euclid(self.height as isize) as usize,
+            col.rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    /// The cell at `pos` on the infinite plane tiled with copies of this grid.
+    pub fn get_wrapped(&self, pos: (isize, isize)) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// The four orthogonal neighbours of `pos` on the infinite plane tiled
+    /// with copies of this grid.
+    pub fn neighbors4_wrapping(&self, pos: (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+        Direction::ALL.into_iter().map(move |dir| dir.apply(pos))
+    }
+}
+
+/// Positions of the marker characters found by `Grid::parse_with_markers`.
+#[derive(Debug, Default)]
+pub(crate) struct Markers(HashMap<char, Vec<(usize, usize)>>);
+
+impl Markers {
+    /// Every `(row, col)` holding `marker`, in reading order.
+    pub fn all(&self, marker: char) -> &[(usize, usize)] {
+        self.0.get(&marker).map_or(&[], |v| v.as_slice())
+    }
+
+    /// The position of `marker`, which must appear exactly once.
+    pub fn one(&self, marker: char) -> Result<(usize, usize), Error> {
+        match self.all(marker) {
+            [pos] => Ok(*pos),
+            found => Err(Error::Invalid(format!(
+                "expected one {:?} in the grid, found {}",
+                marker,
+                found.len()
+            ))),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    #[allow(dead_code)]
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Swaps rows and columns, so `(row, col)` moves to `(col, row)`.
+    #[allow(dead_code)]
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Rotates a quarter turn clockwise: the first column, read bottom to top,
+    /// becomes the first row.
+    #[allow(dead_code)]
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).rev().map(move |row| (row, col)))
+            .map(|pos| self[pos].clone())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Rotates a quarter turn counter-clockwise: the last column, read top to
+    /// bottom, becomes the first row.
+    #[allow(dead_code)]
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|col| (0..self.height).map(move |row| (row, col)))
+            .map(|pos| self[pos].clone())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.width, "column {} out of bounds for width {}", col, self.width);
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.width, "column {} out of bounds for width {}", col, self.width);
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
+
+/// Writes `grid` to stderr, as do the other printers, but only with `-vv`.
+pub(crate) fn print_grid<T>(grid: &Grid<T>, spacing: usize)
 where
-    T: Sized + std::fmt::Display,
+    T: Display,
 {
-    for row in grid.iter() {
+    if !log::enabled(Level::Trace) {
+        return;
+    }
+    for row in grid.rows() {
         for item in row.iter() {
-            print!("{0:>1$}", item, spacing);
+            eprint!("{0:>1$}", item, spacing);
         }
-        println!();
+        eprintln!();
     }
 }
 
 #[allow(dead_code)]
-pub(crate) fn print_grid_pretty<T>(grid: &mut [&mut [T]], spacing: usize, empty: T)
+pub(crate) fn print_grid_pretty<T>(grid: &Grid<T>, spacing: usize, empty: T)
 where
-    T: Sized + Eq + PartialEq + std::fmt::Display,
+    T: Eq + Display,
 {
-    for row in grid.iter() {
+    if !log::enabled(Level::Trace) {
+        return;
+    }
+    for row in grid.rows() {
         for item in row.iter() {
             if *item == empty {
-                print!("{0:>1$}", '.', spacing);
+                eprint!("{0:>1$}", '.', spacing);
             } else {
-                print!("{0:>1$}", item, spacing);
+                eprint!("{0:>1$}", item, spacing);
             }
         }
-        println!();
+        eprintln!();
     }
 }
 
@@ -33,32 +418,111 @@ pub(crate) fn print_gridvec<T>(grid: &[Vec<T>], spacing: usize, dot: T)
 where
     T: Sized + Eq + PartialEq + std::fmt::Display,
 {
+    if !log::enabled(Level::Trace) {
+        return;
+    }
     for row in grid.iter() {
         for item in row.iter() {
             if *item == dot {
-                print!("{0:>1$}", '.', spacing);
+                eprint!("{0:>1$}", '.', spacing);
             } else {
-                print!("{0:>1$}", item, spacing);
+                eprint!("{0:>1$}", item, spacing);
             }
         }
-        println!();
+        eprintln!();
     }
 }
 
-#[allow(dead_code)]
-pub(crate) fn get_grid_neighbors((ni, nj): (usize, usize), size: usize) -> Vec<(usize, usize)> {
-    let mut neighbors = Vec::new();
-    if ni > 0 {
-        neighbors.push((ni - 1, nj));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 2 3
+    // 4 5 6
+    fn sample() -> Grid<u32> {
+        Grid::from_vec(3, vec![1, 2, 3, 4, 5, 6]).unwrap()
     }
-    if nj > 0 {
-        neighbors.push((ni, nj - 1));
+
+    #[test]
+    fn indexing_is_row_major() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid[0], [1, 2, 3]);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.position(|&c| c == 6), Some((1, 2)));
     }
-    if ni < size - 1 {
-        neighbors.push((ni + 1, nj));
+
+    #[test]
+    fn from_vec_rejects_partial_rows() {
+        assert!(Grid::from_vec(4, vec![0; 6]).is_none());
+        assert!(Grid::<u32>::from_vec(0, vec![]).is_none());
+    }
+
+    #[test]
+    fn parse_maps_cells_and_finds_markers() {
+        let (grid, markers) =
+            Grid::parse_with_markers("#S.\n..#\n", &['S'], |c| Some(c == '#')).unwrap();
+        assert_eq!(grid, Grid::from_vec(3, vec![true, false, false, false, false, true]).unwrap());
+        assert_eq!(markers.one('S').unwrap(), (0, 1));
+        assert!(markers.one('E').is_err());
+    }
+
+    #[test]
+    fn parse_rejects_ragged_lines_and_bad_cells() {
+        match Grid::parse("123\n45\n678", |c| c.to_digit(10)) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        match Grid::parse("123\n4x6", |c| c.to_digit(10)) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(Grid::parse("", Some).is_err());
     }
-    if nj < size - 1 {
-        neighbors.push((ni, nj + 1));
+
+    #[test]
+    fn neighbourhoods_stay_on_the_grid() {
+        let grid = sample();
+        let mut corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+        corner.sort();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        let mut edge: Vec<_> = grid.neighbors8((1, 1)).collect();
+        edge.sort();
+        assert_eq!(edge, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]);
+        assert_eq!(grid.step((1, 2), Direction::East), None);
+        assert_eq!(grid.step((1, 2), Direction::North), Some((0, 2)));
+    }
+
+    #[test]
+    fn wrapping_tiles_the_plane() {
+        let grid = sample();
+        assert_eq!(grid.wrap((-1, -1)), (1, 2));
+        assert_eq!(*grid.get_wrapped((2, 4)), 2);
+        assert_eq!(grid.neighbors4_wrapping((0, 0)).count(), 4);
+    }
+
+    #[test]
+    fn directions_turn() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            let (dr, dc) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-dr, -dc));
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = sample();
+        assert_eq!(grid.transpose(), Grid::from_vec(2, vec![1, 4, 2, 5, 3, 6]).unwrap());
+        assert_eq!(grid.rotate_cw(), Grid::from_vec(2, vec![4, 1, 5, 2, 6, 3]).unwrap());
+        assert_eq!(grid.rotate_ccw(), Grid::from_vec(2, vec![3, 6, 2, 5, 1, 4]).unwrap());
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
     }
-    neighbors
 }
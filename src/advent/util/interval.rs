@@ -0,0 +1,342 @@
+use std::fmt::{Debug, Display};
+
+use num_traits::PrimInt;
+
+/// The half-open range of integers `start..end`. Empty when `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Interval<T: PrimInt> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    /// The interval `start..=last`.
+    pub fn inclusive(start: T, last: T) -> Interval<T> {
+        Interval::new(start, last + T::one())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// How many integers the interval holds.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    #[allow(dead_code)]
+    pub fn intersects(&self, other: &Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The values in both intervals, possibly empty.
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn shift(&self, by: T) -> Interval<T> {
+        Interval::new(self.start + by, self.end + by)
+    }
+
+    /// Splits into the values below `at` and the rest, either possibly empty.
+    pub fn split_at(&self, at: T) -> (Interval<T>, Interval<T>) {
+        let at = at.max(self.start).min(self.end.max(self.start));
+        (Interval::new(self.start, at), Interval::new(at, self.end))
+    }
+}
+
+impl<T: PrimInt + Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint, non-adjacent intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct IntervalSet<T: PrimInt> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { intervals: Vec::new() }
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval<T>> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        intervals.sort_by_key(|i| i.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers the set holds.
+    #[allow(dead_code)]
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::zero(), |n, i| n + i.len())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    #[allow(dead_code)]
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.end - T::one())
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, x: T) -> bool {
+        self.intervals.iter().any(|i| i.contains(x))
+    }
+
+    #[allow(dead_code)]
+    pub fn insert(&mut self, interval: Interval<T>) {
+        *self = self.union(&IntervalSet::from(interval));
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intervals.iter().chain(other.intervals.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            let both = a.intersection(&b);
+            if !both.is_empty() {
+                intervals.push(both);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values in `self` but not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for a in &self.intervals {
+            while j < other.intervals.len() && other.intervals[j].end <= a.start {
+                j += 1;
+            }
+            let mut start = a.start;
+            for b in other.intervals[j..].iter().take_while(|b| b.start < a.end) {
+                if start < b.start {
+                    intervals.push(Interval::new(start, b.start));
+                }
+                start = start.max(b.end);
+            }
+            if start < a.end {
+                intervals.push(Interval::new(start, a.end));
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn shift(&self, by: T) -> IntervalSet<T> {
+        IntervalSet {
+            intervals: self.intervals.iter().map(|i| i.shift(by)).collect(),
+        }
+    }
+}
+
+/// A map from integers to integers that shifts each of its pieces by that
+/// piece's offset and leaves every other value where it is. Where pieces
+/// overlap, the one inserted first wins.
+#[derive(Debug, Clone)]
+pub(crate) struct Piecewise<T: PrimInt> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: PrimInt> Default for Piecewise<T> {
+    fn default() -> Self {
+        Piecewise { pieces: Vec::new() }
+    }
+}
+
+impl<T: PrimInt> Piecewise<T> {
+    pub fn new() -> Piecewise<T> {
+        Piecewise::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>, offset: T) {
+        self.pieces.push((interval, offset));
+    }
+
+    pub fn apply(&self, x: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(interval, _)| interval.contains(x))
+            .map_or(x, |&(_, offset)| x + offset)
+    }
+
+    /// The image of every value in `set`.
+    pub fn apply_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut rest = set.clone();
+        let mut image = IntervalSet::new();
+        for &(interval, offset) in &self.pieces {
+            let hit = rest.intersection(&IntervalSet::from(interval));
+            image = image.union(&hit.shift(offset));
+            rest = rest.difference(&hit);
+        }
+        image.union(&rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals.iter().map(|&(s, e)| Interval::new(s, e)).collect()
+    }
+
+    fn bounds(set: &IntervalSet<i64>) -> Vec<(i64, i64)> {
+        set.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn interval_basics() {
+        let i = Interval::inclusive(3, 7);
+        assert_eq!(i, Interval::new(3, 8));
+        assert_eq!(i.len(), 5);
+        assert!(i.contains(7) && !i.contains(8));
+        assert!(Interval::new(5, 5).is_empty());
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert_eq!(i.split_at(5), (Interval::new(3, 5), Interval::new(5, 8)));
+        assert!(i.split_at(1).0.is_empty());
+        assert!(i.split_at(10).1.is_empty());
+        assert!(!i.intersects(&Interval::new(8, 10)));
+    }
+
+    #[test]
+    fn sets_merge_overlapping_and_adjacent() {
+        let s = set(&[(10, 12), (1, 3), (3, 5), (4, 6), (7, 7)]);
+        assert_eq!(bounds(&s), vec![(1, 6), (10, 12)]);
+        assert_eq!(s.len(), 7);
+        assert_eq!((s.min(), s.max()), (Some(1), Some(11)));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(bounds(&a.union(&b)), vec![(0, 40)]);
+        assert_eq!(bounds(&a.intersection(&b)), vec![(5, 10), (20, 25), (28, 30)]);
+        assert_eq!(bounds(&a.difference(&b)), vec![(0, 5), (25, 28)]);
+        assert_eq!(bounds(&b.difference(&a)), vec![(10, 20), (30, 40)]);
+        assert_eq!(bounds(&a.shift(-5)), vec![(-5, 5), (15, 25)]);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    type Bounds = Vec<(i64, i64)>;
+
+    /// The image under a one-piece map of the values of `from` the piece
+    /// covers, and of those it does not, as inclusive bounds.
+    fn transform(from: (i64, i64), piece: (i64, i64), offset: i64) -> (Bounds, Bounds) {
+        let piece = Interval::inclusive(piece.0, piece.1);
+        let mut map = Piecewise::new();
+        map.insert(piece, offset);
+        let from = IntervalSet::from(Interval::inclusive(from.0, from.1));
+        let inclusive = |set: IntervalSet<i64>| set.iter().map(|i| (i.start, i.end - 1)).collect();
+        let covered = from.intersection(&piece.into());
+        let rest = from.difference(&piece.into());
+        (inclusive(map.apply_set(&covered)), inclusive(map.apply_set(&rest)))
+    }
+
+    #[test]
+    fn piecewise_disjoint() {
+        let (moved, rest) = transform((1, 5), (10, 20), 3);
+        assert!(moved.is_empty());
+        assert_eq!(rest, vec![(1, 5)]);
+    }
+
+    #[test]
+    fn piecewise_contained() {
+        let (moved, rest) = transform((12, 15), (10, 20), 3);
+        assert_eq!(moved, vec![(15, 18)]);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn piecewise_overlapping() {
+        let (moved, rest) = transform((5, 12), (10, 20), 3);
+        assert_eq!(moved, vec![(13, 15)]);
+        assert_eq!(rest, vec![(5, 9)]);
+
+        let (moved, rest) = transform((15, 25), (10, 20), -10);
+        assert_eq!(moved, vec![(5, 10)]);
+        assert_eq!(rest, vec![(21, 25)]);
+    }
+
+    #[test]
+    fn piecewise_covering() {
+        let (moved, rest) = transform((5, 25), (10, 20), -10);
+        assert_eq!(moved, vec![(0, 10)]);
+        assert_eq!(rest, vec![(5, 9), (21, 25)]);
+    }
+
+    #[test]
+    fn piecewise_single_shared_value() {
+        let (moved, rest) = transform((5, 10), (10, 20), 3);
+        assert_eq!(moved, vec![(13, 13)]);
+        assert_eq!(rest, vec![(5, 9)]);
+    }
+
+    #[test]
+    fn piecewise_first_piece_wins() {
+        let mut map = Piecewise::new();
+        map.insert(Interval::new(0, 10), 100);
+        map.insert(Interval::new(5, 15), 1000);
+        assert_eq!(map.apply(7), 107);
+        assert_eq!(map.apply(12), 1012);
+        assert_eq!(map.apply(20), 20);
+        assert_eq!(bounds(&map.apply_set(&set(&[(0, 20)]))), vec![(15, 20), (100, 110), (1010, 1015)]);
+    }
+}
@@ -43,23 +43,220 @@ pub(crate) fn lcm(u: i64, v: i64) -> i64 {
     }
 }
 
+/// `lcm`, or `None` if the result does not fit in an `i64`.
 #[allow(dead_code)]
-pub (crate) fn polygon_area(vertices: &[(i64, i64)]) -> f64 {
-    let n = vertices.len();
-    let mut sum = 0.0;
+pub(crate) fn checked_lcm(u: i64, v: i64) -> Option<i64> {
+    if u == 0 || v == 0 {
+        return Some(0);
+    }
+    (u / gcd(u, v)).checked_mul(v)?.checked_abs()
+}
+
+/// Greatest common divisor of two `i128`s, always non-negative.
+pub(crate) fn gcd_i128(u: i128, v: i128) -> i128 {
+    let (mut a, mut b) = (u.unsigned_abs(), v.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a as i128
+}
+
+/// Least common multiple of two `i128`s, or `None` if it does not fit.
+#[allow(dead_code)]
+pub(crate) fn checked_lcm_i128(u: i128, v: i128) -> Option<i128> {
+    if u == 0 || v == 0 {
+        return Some(0);
+    }
+    (u / gcd_i128(u, v)).checked_mul(v)?.checked_abs()
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g`, where `g` is the
+/// non-negative greatest common divisor of `a` and `b`.
+pub(crate) fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub(crate) fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves the system `x ≡ offset (mod period)` for every `(offset, period)`
+/// pair, whose periods need not be coprime. Returns `(x, lcm)` with `x` in
+/// `0..lcm`, the solutions being exactly `x + k * lcm`. `None` if the
+/// congruences contradict each other, a period is not positive, or the
+/// numbers outgrow an `i128`.
+pub(crate) fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let (mut x, mut m) = (0, 1);
+    for (offset, period) in congruences {
+        if period <= 0 {
+            return None;
+        }
+        let g = gcd_i128(m, period);
+        let diff = offset - x;
+        if diff % g != 0 {
+            return None;
+        }
+        // solve m * k ≡ diff (mod period) for k
+        let step = period / g;
+        let inverse = mod_inverse(m / g, step)?;
+        let k = ((diff / g).rem_euclid(step)).checked_mul(inverse)?.rem_euclid(step);
+        let lcm = (m / g).checked_mul(period)?;
+        x = x.checked_add(m.checked_mul(k)?)?.rem_euclid(lcm);
+        m = lcm;
+    }
+    Some((x, m))
+}
+
+/// The smallest `n >= lower` with `n ≡ offset (mod period)`.
+pub(crate) fn next_congruent(lower: i128, offset: i128, period: i128) -> i128 {
+    lower + (offset - lower).rem_euclid(period)
+}
 
+/// The corners reached by following `moves`, each a unit `(dx, dy)` step and
+/// how many times to take it, starting from the origin.
+pub(crate) fn trace(moves: impl IntoIterator<Item = ((i64, i64), i64)>) -> Vec<(i64, i64)> {
+    let mut vertices = vec![(0, 0)];
+    let mut pos = (0, 0);
+    for ((dx, dy), n) in moves {
+        pos = (pos.0 + dx * n, pos.1 + dy * n);
+        vertices.push(pos);
+    }
+    if vertices.len() > 1 && vertices.last() == vertices.first() {
+        vertices.pop();
+    }
+    vertices
+}
+
+/// Twice the area enclosed by the polygon through `vertices`, by the
+/// shoelace formula. Doubling keeps it an integer for lattice polygons.
+pub(crate) fn double_area(vertices: &[(i64, i64)]) -> i128 {
+    let n = vertices.len();
+    let mut sum: i128 = 0;
     for cur in 0..n {
-        let next = (cur + 1) % n;
-        sum += (vertices[cur].0 as f64 * vertices[next].1 as f64) - (vertices[next].0 as f64 * vertices[cur].1 as f64);
+        let (x0, y0) = vertices[cur];
+        let (x1, y1) = vertices[(cur + 1) % n];
+        sum += x0 as i128 * y1 as i128 - x1 as i128 * y0 as i128;
     }
+    sum.abs()
+}
 
-    0.5 * sum.abs()
+/// Lattice points on the boundary of the polygon through `vertices`, without
+/// walking each edge: an edge from `a` to `b` passes through
+/// `gcd(|dx|, |dy|)` of them, counting one of its ends.
+pub(crate) fn boundary_points(vertices: &[(i64, i64)]) -> i128 {
+    let n = vertices.len();
+    (0..n)
+        .map(|cur| {
+            let (x0, y0) = vertices[cur];
+            let (x1, y1) = vertices[(cur + 1) % n];
+            gcd_i128(x1 as i128 - x0 as i128, y1 as i128 - y0 as i128)
+        })
+        .sum()
 }
 
-#[allow(dead_code)]
-pub (crate) fn picks_theorem(area: f64, vertices: &[(i64, i64)]) -> u64 {
-    let boundary_points = vertices.len() as u64;
-    let interior_points = area - (boundary_points / 2) as f64 + 1.0;
+/// How many lattice points lie inside and on a simple polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LatticePoints {
+    pub interior: i128,
+    pub boundary: i128,
+}
+
+impl LatticePoints {
+    pub fn total(&self) -> i128 {
+        self.interior + self.boundary
+    }
+}
 
-    interior_points as u64
+/// Counts the lattice points of a simple polygon whose vertices lie on the
+/// lattice, by Pick's theorem: `A = I + B/2 - 1`.
+pub(crate) fn lattice_points(vertices: &[(i64, i64)]) -> LatticePoints {
+    let boundary = boundary_points(vertices);
+    LatticePoints {
+        interior: (double_area(vertices) - boundary + 2) / 2,
+        boundary,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lcm_overflow_is_caught() {
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(-4, 6), Some(12));
+        assert_eq!(checked_lcm(i64::MAX, i64::MAX - 1), None);
+        assert_eq!(checked_lcm_i128(i64::MAX as i128, i64::MAX as i128 - 1), Some(i64::MAX as i128 * (i64::MAX as i128 - 1)));
+        assert_eq!(gcd_i128(-12, 18), 6);
+    }
+
+    #[test]
+    fn extended_gcd_and_inverse() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        let (g, x, y) = extended_gcd(-7, 3);
+        assert_eq!(g, 1);
+        assert_eq!(-7 * x + 3 * y, 1);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // periods sharing factors
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([(7, 10), (7, 10)]), Some((7, 10)));
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, 0)]), None);
+        assert_eq!(next_congruent(10, 4, 5), 14);
+        assert_eq!(next_congruent(14, 4, 5), 14);
+        assert_eq!(next_congruent(0, -1, 5), 4);
+    }
+
+    #[test]
+    fn lattice_polygons() {
+        let rectangle = [(0, 0), (4, 0), (4, 3), (0, 3)];
+        assert_eq!(double_area(&rectangle), 24);
+        assert_eq!(lattice_points(&rectangle), LatticePoints { interior: 6, boundary: 14 });
+        // odd boundary counts
+        assert_eq!(lattice_points(&[(0, 0), (1, 0), (0, 1)]), LatticePoints { interior: 0, boundary: 3 });
+        assert_eq!(lattice_points(&[(0, 0), (3, 0), (0, 3)]), LatticePoints { interior: 1, boundary: 9 });
+        // clockwise is the same as counter-clockwise
+        let mut reversed = rectangle;
+        reversed.reverse();
+        assert_eq!(lattice_points(&reversed), lattice_points(&rectangle));
+    }
+
+    #[test]
+    fn traced_runs() {
+        let vertices = trace([((1, 0), 4), ((0, 1), 3), ((-1, 0), 4), ((0, -1), 3)]);
+        assert_eq!(vertices, vec![(0, 0), (4, 0), (4, 3), (0, 3)]);
+        // coordinates whose products overflow an i64
+        let big = 4_000_000_000;
+        let square = trace([((1, 0), big), ((0, 1), big), ((-1, 0), big), ((0, -1), big)]);
+        assert_eq!(lattice_points(&square).total(), (big as i128 + 1) * (big as i128 + 1));
+    }
 }
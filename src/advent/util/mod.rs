@@ -1,7 +1,13 @@
 pub mod math;
+pub mod cycle;
+pub mod graph;
 pub mod grid;
 pub mod grid3d;
+pub mod interval;
 pub mod debug;
 pub mod parse;
+pub mod path;
+pub mod poly;
+pub mod pool;
 
 pub(crate) use self::parse::parse_in_lines;
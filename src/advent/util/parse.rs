@@ -1,15 +1,104 @@
-use std::fs::File;
-use std::{io, io::prelude::*};
-
-#[allow(dead_code)]
-pub(crate) fn parse_in_lines(
-    path: &str,
-) -> Result<Box<dyn Iterator<Item = String>>, Box<dyn std::error::Error>> {
-    let input = File::open(path)?;
-    let reader = io::BufReader::new(input);
-
-    let iter = reader
-        .lines()
-        .filter_map(|l| Some(l.ok()?.trim_end().to_owned()));
-    Ok(Box::new(iter))
+use nom::bytes::complete::tag;
+use nom::character::complete::{newline, space0, space1};
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::{pair, separated_pair, tuple};
+use nom::{IResult, Parser};
+
+use crate::advent::Error;
+
+type NomError<'a> = nom::error::Error<&'a str>;
+
+pub(crate) fn parse_in_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end)
+}
+
+/// Runs `parser` over `input`, failing with a located error if it does not
+/// match or leaves anything behind.
+pub(crate) fn parse_complete<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, NomError<'a>>,
+) -> Result<O, Error> {
+    let (rem, out) = parser.parse(input).map_err(|e| Error::nom(input, e))?;
+    if !rem.is_empty() {
+        return Err(Error::remainder(input, rem));
+    }
+    Ok(out)
+}
+
+/// One or more `item`s separated by runs of spaces, as in `41 48  83`.
+pub(crate) fn spaced<'a, O>(
+    item: impl Parser<&'a str, O, NomError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(space1, item)
+}
+
+/// One or more `item`s, one per line.
+pub(crate) fn lines<'a, O>(
+    item: impl Parser<&'a str, O, NomError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(newline, item)
+}
+
+/// One or more `item`s separated by blank lines.
+pub(crate) fn blocks<'a, O>(
+    item: impl Parser<&'a str, O, NomError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(tag("\n\n"), item)
+}
+
+/// A node and the nodes it leads to, as in `a -> b, c` or `a: b c`.
+pub(crate) fn edges<'a, F, T>(
+    from: impl Parser<&'a str, F, NomError<'a>>,
+    arrow: &'a str,
+    to: impl Parser<&'a str, T, NomError<'a>>,
+    separator: &'a str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (F, Vec<T>)> {
+    separated_pair(from, tag(arrow), separated_list1(tag(separator), to))
+}
+
+/// Three `number`s separated by `separator`, which may be followed by
+/// spaces, as in `1,0,1` or `19, 13, 30`.
+pub(crate) fn triple<'a, O>(
+    number: impl Parser<&'a str, O, NomError<'a>> + Copy,
+    separator: &'a str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (O, O, O)> {
+    let sep = move |input: &'a str| pair(tag(separator), space0)(input);
+    map(
+        tuple((number, sep, number, sep, number)),
+        |(x, _, y, _, z)| (x, y, z),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::{alpha1, i64, u32};
+
+    #[test]
+    fn shapes() {
+        assert_eq!(spaced(u32)("41 48  83|"), Ok(("|", vec![41, 48, 83])));
+        assert_eq!(lines(spaced(i64))("1 -2\n3"), Ok(("", vec![vec![1, -2], vec![3]])));
+        assert_eq!(blocks(lines(alpha1))("a\nb\n\nc"), Ok(("", vec![vec!["a", "b"], vec!["c"]])));
+        assert_eq!(
+            edges(alpha1, " -> ", alpha1, ", ")("a -> b, c"),
+            Ok(("", ("a", vec!["b", "c"])))
+        );
+        assert_eq!(edges(alpha1, ": ", alpha1, " ")("a: b c"), Ok(("", ("a", vec!["b", "c"]))));
+        assert_eq!(triple(u32, ",")("1,0,1~"), Ok(("~", (1, 0, 1))));
+        assert_eq!(triple(i64, ",")("19, 13,  -30 @"), Ok((" @", (19, 13, -30))));
+    }
+
+    #[test]
+    fn parse_complete_rejects_leftovers() {
+        assert_eq!(parse_complete("1 2 3", spaced(u32)).unwrap(), vec![1, 2, 3]);
+        match parse_complete("1 2\nx", spaced(u32)) {
+            Err(Error::Remainder { line: 1, column: 4, .. }) => {}
+            other => panic!("{:?}", other),
+        }
+        match parse_complete("x", spaced(u32)) {
+            Err(Error::Parse { line: 1, column: 1, .. }) => {}
+            other => panic!("{:?}", other),
+        }
+    }
 }
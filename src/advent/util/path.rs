@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num_traits::Zero;
+
+/// A cheapest route found by `dijkstra` or `astar`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Path<S, C> {
+    pub cost: C,
+    /// Every state visited, from the start up to and including the goal.
+    pub states: Vec<S>,
+}
+
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Entry<C> {
+    // BinaryHeap is a max-heap: the lowest estimate must compare greatest, and
+    // among equal estimates the one furthest along wins.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// Finds the cheapest path from any of `starts` to a state satisfying
+/// `is_goal`. `successors` lists the states reachable from a state along with
+/// the cost of each move, which must not be negative.
+#[allow(dead_code)]
+pub(crate) fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// Like `dijkstra`, guided by `heuristic`, an estimate of the remaining cost
+/// to a goal. The result is only guaranteed cheapest if the heuristic never
+/// overestimates.
+pub(crate) fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // every state seen so far, with its parent and the cheapest known cost
+    let mut nodes: Vec<(S, usize, C)> = Vec::new();
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if indices.contains_key(&start) {
+            continue;
+        }
+        let index = nodes.len();
+        indices.insert(start.clone(), index);
+        heap.push(Entry {
+            estimate: heuristic(&start),
+            cost: C::zero(),
+            index,
+        });
+        nodes.push((start, usize::MAX, C::zero()));
+    }
+
+    while let Some(Entry { cost, index, .. }) = heap.pop() {
+        if cost > nodes[index].2 {
+            // superseded by a cheaper route to the same state
+            continue;
+        }
+        if is_goal(&nodes[index].0) {
+            let mut states = Vec::new();
+            let mut current = index;
+            while current != usize::MAX {
+                states.push(nodes[current].0.clone());
+                current = nodes[current].1;
+            }
+            states.reverse();
+            return Some(Path { cost, states });
+        }
+        for (next, step) in successors(&nodes[index].0) {
+            let next_cost = cost + step;
+            let next_index = match indices.get(&next) {
+                Some(&i) if nodes[i].2 <= next_cost => continue,
+                Some(&i) => {
+                    nodes[i].1 = index;
+                    nodes[i].2 = next_cost;
+                    i
+                }
+                None => {
+                    let i = nodes.len();
+                    indices.insert(next.clone(), i);
+                    nodes.push((next.clone(), index, next_cost));
+                    i
+                }
+            };
+            heap.push(Entry {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 2 -1-> 3, with a direct but dearer 0 -5-> 3
+    fn successors(&n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        let path = dijkstra([0], successors, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn astar_agrees_with_an_admissible_heuristic() {
+        let path = astar([0], successors, |&n| 3 - n, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn unreachable_goal_and_multiple_starts() {
+        assert_eq!(dijkstra([1], successors, |&n| n == 0), None);
+        let path = dijkstra([0, 2], successors, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.states, vec![2, 3]);
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::math::gcd_i128;
+
+/// An exact fraction, always in lowest terms with a positive denominator.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    /// Panics if `den` is zero.
+    #[allow(dead_code)]
+    pub fn new(num: i128, den: i128) -> Rational {
+        assert!(den != 0, "zero denominator");
+        let g = gcd_i128(num, den) * den.signum();
+        Rational {
+            num: num / g,
+            den: den / g,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn numer(&self) -> i128 {
+        self.num
+    }
+
+    #[allow(dead_code)]
+    pub fn denom(&self) -> i128 {
+        self.den
+    }
+
+    #[allow(dead_code)]
+    pub fn to_integer(self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Rational { num: n, den: 1 }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.den + other.num * self.den, self.den * other.den)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.num, self.den * other.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    /// Panics when dividing by zero.
+    fn div(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.den, self.den * other.num)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Rational {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// The polynomial through samples taken at `0, 1, 2, ...`, held as Newton's
+/// forward differences. Integer samples give integer differences, and the
+/// polynomial takes integer values at every integer, so no fractions are
+/// needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Newton {
+    /// The first entry of each row of the difference table.
+    differences: Vec<i128>,
+    degree: Option<usize>,
+}
+
+impl Newton {
+    pub fn fit(samples: &[i128]) -> Newton {
+        let mut differences = Vec::new();
+        let mut degree = None;
+        let mut row = samples.to_vec();
+        while let Some(&first) = row.first() {
+            if row.iter().all(|&x| x == 0) {
+                degree = Some(differences.len().saturating_sub(1));
+                break;
+            }
+            differences.push(first);
+            row = row.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+        Newton { differences, degree }
+    }
+
+    /// The lowest degree polynomial through the samples, if the samples were
+    /// enough to show it: a row of the difference table must have come out all
+    /// zeros. The zero polynomial counts as degree 0.
+    pub fn degree(&self) -> Option<usize> {
+        self.degree
+    }
+
+    /// The polynomial's value at `x`, which may lie before the first sample.
+    pub fn eval(&self, x: i128) -> i128 {
+        // x choose j, which stays an integer for negative x too
+        let mut binomial = 1;
+        let mut sum = 0;
+        for (j, &difference) in self.differences.iter().enumerate() {
+            sum += binomial * difference;
+            binomial = binomial * (x - j as i128) / (j as i128 + 1);
+        }
+        sum
+    }
+}
+
+/// The value at `x` of the polynomial through `points`, whose x coordinates
+/// must all differ but need not be evenly spaced.
+#[allow(dead_code)]
+pub(crate) fn lagrange(points: &[(i128, i128)], x: i128) -> Rational {
+    let mut sum = Rational::from(0);
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        let mut term = Rational::from(yi);
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i != j {
+                term = term * Rational::new(x - xj, xi - xj);
+            }
+        }
+        sum = sum + term;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rationals_stay_reduced() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(1, 3) + Rational::new(1, 6), Rational::new(1, 2));
+        assert_eq!(Rational::new(1, 2) - Rational::from(1), Rational::new(-1, 2));
+        assert_eq!(Rational::new(2, 3) / Rational::new(4, 9), Rational::new(3, 2));
+        assert_eq!((Rational::new(3, 2) * Rational::from(2)).to_integer(), Some(3));
+        assert_eq!(Rational::new(-3, 2).to_string(), "-3/2");
+    }
+
+    #[test]
+    fn newton_extrapolates_both_ways() {
+        let squares = Newton::fit(&[0, 1, 4, 9]);
+        assert_eq!(squares.degree(), Some(2));
+        assert_eq!(squares.eval(10), 100);
+        assert_eq!(squares.eval(-3), 9);
+
+        let cubes = Newton::fit(&[-8, -1, 0, 1, 8, 27]);
+        assert_eq!(cubes.degree(), Some(3));
+        assert_eq!(cubes.eval(-100), -102 * 102 * 102);
+
+        assert_eq!(Newton::fit(&[5, 5]).degree(), Some(0));
+        assert_eq!(Newton::fit(&[0, 0]).eval(7), 0);
+        // three samples never show that they lie on a parabola
+        let unknown = Newton::fit(&[1, 2, 4]);
+        assert_eq!(unknown.degree(), None);
+        assert_eq!(unknown.eval(3), 7);
+    }
+
+    #[test]
+    fn lagrange_on_uneven_points() {
+        let points = [(0, 0), (2, 4), (3, 9)];
+        assert_eq!(lagrange(&points, 5), Rational::from(25));
+        assert_eq!(lagrange(&points, -4), Rational::from(16));
+        assert_eq!(lagrange(&[(0, 0), (2, 1)], 1), Rational::new(1, 2));
+    }
+}
@@ -0,0 +1,123 @@
+use std::cell::Cell;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// The number of worker threads, or 0 for one per available core.
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// The stack each worker gets: as much as the main thread, where the solvers
+/// used to run, since some of them recurse deeply in debug builds.
+const STACK_SIZE: usize = 8 << 20;
+
+thread_local! {
+    static IN_POOL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Caps the number of worker threads `map` uses. 1 runs everything on the
+/// calling thread, 0 restores the default of one per core.
+pub fn set_threads(n: usize) {
+    THREADS.store(n, Ordering::Relaxed);
+}
+
+/// The number of worker threads `map` uses.
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    }
+}
+
+/// Applies `f` to every item, spreading the items over the worker threads, and
+/// returns the results in the order of the items. Calls made from inside a
+/// worker run sequentially rather than starting another pool.
+pub(crate) fn map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    map_with(threads(), items, f)
+}
+
+/// `map` over at most `workers` threads, whatever `set_threads` was given.
+fn map_with<T, R>(workers: usize, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let workers = workers.min(items.len());
+    if workers <= 1 || IN_POOL.with(Cell::get) {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let done: Vec<Vec<(usize, R)>> = thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                let worker = thread::Builder::new().stack_size(STACK_SIZE);
+                let spawned = worker.spawn_scoped(s, || {
+                    IN_POOL.with(|p| p.set(true));
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        match items.get(i) {
+                            Some(item) => done.push((i, f(item))),
+                            None => break done,
+                        }
+                    }
+                });
+                spawned.expect("failed to spawn a worker thread")
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+
+    let mut results: Vec<Option<R>> = (0..items.len()).map(|_| None).collect();
+    for (i, r) in done.into_iter().flatten() {
+        results[i] = Some(r);
+    }
+    results
+        .into_iter()
+        .map(|r| r.expect("every item is taken by exactly one worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        let squares = map(&items, |&x| x * x);
+        assert_eq!(squares, items.iter().map(|&x| x * x).collect::<Vec<_>>());
+        assert!(map(&[] as &[u64], |&x| x).is_empty());
+    }
+
+    #[test]
+    fn nested_calls_run_inline() {
+        let rows: Vec<Vec<u64>> = (0..8).map(|i| (0..i).collect()).collect();
+        let sums = map(&rows, |row| map(row, |&x| x).iter().sum::<u64>());
+        assert_eq!(sums, vec![0, 0, 1, 3, 6, 10, 15, 21]);
+    }
+
+    /// Recurses `n` deep with frames of a few hundred bytes.
+    fn depth(n: u64) -> u64 {
+        let pad = std::hint::black_box([n; 32]);
+        if n == 0 {
+            return 0;
+        }
+        let below = depth(n - 1);
+        std::hint::black_box(&pad);
+        1 + below
+    }
+
+    #[test]
+    fn workers_get_a_main_sized_stack() {
+        // too deep for the 2 MiB a spawned thread gets by default
+        assert_eq!(map_with(2, &[12_000, 12_000], |&n| depth(n)), vec![12_000, 12_000]);
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Answer, Error, Solution};
+use super::log::trace;
+use super::timing;
+use super::util;
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let lines: Vec<&str> = util::parse_in_lines(input).collect();
+    timing::parsed();
+    let mut numbers = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        let v: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
+        match (v.first(), v.last()) {
+            (Some(f), Some(l)) => numbers.push(f * 10 + l),
+            _ => return Err(Error::Invalid(format!("No digits on line {}", idx + 1))),
+        }
+    }
+    Ok(Answer::from(numbers.iter().sum::<u32>()))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let lines: Vec<&str> = util::parse_in_lines(input).collect();
+    timing::parsed();
+    let mut numbers = Vec::new();
+    let numstrings: Vec<(&str, u32)> = Vec::from([
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+    ]);
+    for line in lines {
+        let mut first: Option<u32> = Option::None;
+        let mut last: Option<u32> = Option::None;
+        for i in 0..line.len() {
+            let t = line
+                .get(i..)
+                .ok_or(Error::Invalid(format!("Non-ASCII input: {}", line)))?;
+            numstrings.iter().for_each(|(s, i)| {
+                if t.starts_with(s) {
+                    last = Some(*i);
+                    if first.is_none() {
+                        first = last;
+                    }
+                }
+            });
+        }
+        if let (Some(f), Some(l)) = (first, last) {
+            trace!("line: {} -- {}{}", line, f, l);
+            numbers.push(f * 10 + l);
+        }
+    }
+    Ok(Answer::from(numbers.iter().sum::<u32>()))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Trebuchet?!"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -1,4 +1,7 @@
+use super::{Answer, Error, Solution};
+use super::timing;
 use super::util;
+use super::util::parse::parse_complete;
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
@@ -26,17 +29,6 @@ enum Color {
     Blue(u32),
 }
 
-fn parse_line(input: &str) -> Result<Game, Box<dyn std::error::Error + '_>> {
-    let (remainder, game) = parse_game(input)?;
-    if !remainder.is_empty() {
-        return Err(Box::new(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            format!("Remainder remaining after parsing game: {}", remainder),
-        )));
-    }
-    Ok(game)
-}
-
 fn parse_game(input: &str) -> IResult<&str, Game> {
     let (remainder, id) = delimited(tag("Game "), u32, tag(": "))(input)?;
     let (remainder, samples) = separated_list0(tag("; "), parse_sample)(remainder)?;
@@ -78,16 +70,23 @@ fn parse_color(input: &str) -> IResult<&str, Color> {
     ))
 }
 
-pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let lines = util::parse_in_lines(&path)?;
+fn parse_games(input: &str) -> Result<Vec<Game>, Error> {
+    util::parse_in_lines(input)
+        .enumerate()
+        .map(|(idx, line)| parse_complete(line, parse_game).map_err(|e| e.on_line(idx + 1)))
+        .collect()
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let games = parse_games(input)?;
+    timing::parsed();
     let bag = Sample {
         red: 12,
         green: 13,
         blue: 14,
     };
     let mut sum = 0;
-    'outer: for line in lines {
-        let game = parse_line(&line).unwrap();
+    'outer: for game in games {
         for sample in game.samples {
             if sample.red > bag.red || sample.green > bag.green || sample.blue > bag.blue {
                 continue 'outer;
@@ -95,20 +94,42 @@ pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
         }
         sum += game.id;
     }
-    println!("Sum of game IDs: {}", sum);
-    Ok(())
+    Ok(Answer::from(sum))
 }
 
-pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let lines = util::parse_in_lines(&path)?;
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let games = parse_games(input)?;
+    timing::parsed();
     let mut sum = 0;
-    for line in lines {
-        let game = parse_line(&line).unwrap();
+    for game in games {
         let (r, g, b) = game.samples.iter().fold((0, 0, 0), |(r, g, b), e| {
             (r.max(e.red), g.max(e.green), b.max(e.blue))
         });
         sum += r * g * b;
     }
-    println!("Sum of game IDs: {}", sum);
-    Ok(())
+    Ok(Answer::from(sum))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Cube Conundrum"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
 }
@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util;
+use super::util::grid::Grid;
+
+const MARK: char = 'm';
+const EMPTY: char = '.';
+
+/// The number spelled by `digits`.
+fn number<'a>(digits: impl IntoIterator<Item = &'a char>) -> Result<u64, Error> {
+    let digits: String = digits.into_iter().collect();
+    digits
+        .parse()
+        .map_err(|e| Error::Invalid(format!("Bad part number {:?}: {}", digits, e)))
+}
+
+fn is_part(grid: &Grid<char>, line: usize, start: usize, end: usize) -> bool {
+    (start..=end).any(|j| grid.neighbors8((line, j)).any(|pos| grid[pos] == MARK))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let mut sum = 0;
+
+    let grid = Grid::parse(input, |c| {
+        Some(match c {
+            '.' => EMPTY,
+            x if x.is_ascii_digit() => x,
+            _ => MARK,
+        })
+    })?;
+    timing::parsed();
+    let width = grid.width();
+
+    for i in 0..grid.height() {
+        let mut j = 0;
+        while j < width {
+            let jstart = j;
+            if !grid[i][jstart].is_ascii_digit() {
+                j += 1;
+                continue;
+            }
+            let mut end = width;
+            for k in jstart..width {
+                if !grid[i][k].is_ascii_digit() {
+                    end = k;
+                    break;
+                }
+                j += 1;
+            }
+            if !is_part(&grid, i, jstart, end - 1) {
+                j += 1;
+                continue;
+            }
+            let part = number(&grid[i][jstart..end])?;
+            sum += part;
+            j += 1;
+        }
+    }
+    Ok(Answer::from(sum))
+}
+
+fn find_gears(grid: &Grid<char>, i: usize, j: usize) -> Result<Option<u64>, Error> {
+    let mut positions = HashSet::<(usize, usize)>::new();
+    for (is, js) in grid.neighbors8((i, j)) {
+        if !grid[is][js].is_ascii_digit() {
+            continue;
+        }
+        let mut numstart = js;
+        for x in (0..=js).rev() {
+            if !grid[is][x].is_ascii_digit() {
+                break;
+            }
+            numstart = x;
+        }
+        positions.insert((is,numstart));
+    }
+    if positions.len() != 2 {
+        return Ok(None)
+    }
+    let mut gear: u64 = 1;
+    for (x,y) in positions {
+        let part = number(grid[x][y..].iter().take_while(|c| c.is_ascii_digit()))?;
+        gear = gear
+            .checked_mul(part)
+            .ok_or_else(|| Error::Invalid(format!("Gear ratio at {:?} overflows", (i, j))))?;
+    }
+    Ok(Some(gear))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let mut sum = 0;
+
+    let grid = Grid::parse(input, |c| {
+        Some(match c {
+            x if x.is_ascii_digit() => x,
+            '*' => MARK,
+            _ => EMPTY,
+        })
+    })?;
+    timing::parsed();
+    util::grid::print_grid(&grid, 1);
+    for ((i, j), &c) in grid.indexed() {
+        if c != MARK {
+            continue;
+        }
+        if let Some(gear) = find_gears(&grid, i, j)? {
+            sum += gear;
+        }
+    }
+    Ok(Answer::from(sum))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Gear Ratios"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
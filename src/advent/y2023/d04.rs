@@ -0,0 +1,114 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Answer, Error, Solution};
+use super::log::trace;
+use super::timing;
+use super::util;
+use super::util::parse::{parse_complete, spaced};
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{space1, u32};
+use nom::sequence::{delimited, separated_pair, tuple};
+use nom::IResult;
+
+#[derive(Debug)]
+struct Card {
+    id: u32,
+    winning: HashSet<u32>,
+    played: HashSet<u32>,
+}
+
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    let (remainder, id) = delimited(
+        tuple((tag("Card"), space1)),
+        u32,
+        tuple((tag(":"), space1)),
+    )(input)?;
+    let (remainder, (v_win, v_played)) = separated_pair(
+        spaced(u32),
+        tuple((space1, tag("|"), space1)),
+        spaced(u32),
+    )(remainder)?;
+
+    let winning: HashSet<u32> = v_win.into_iter().collect();
+    let played: HashSet<u32> = v_played.into_iter().collect();
+
+    Ok((
+        remainder,
+        Card {
+            id,
+            winning,
+            played,
+        },
+    ))
+}
+
+fn parse_cards(input: &str) -> Result<Vec<Card>, Error> {
+    util::parse_in_lines(input)
+        .enumerate()
+        .map(|(idx, line)| parse_complete(line, parse_card).map_err(|e| e.on_line(idx + 1)))
+        .collect()
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let cards = parse_cards(input)?;
+    timing::parsed();
+    let mut sum = 0;
+    for card in cards {
+        let amount = card.winning.intersection(&card.played).count();
+        trace!("{:?}", card);
+        if amount > 0 {
+            sum += 1 << (amount - 1)
+        }
+    }
+    Ok(Answer::from(sum))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let cards = parse_cards(input)?;
+    timing::parsed();
+    let mut hm: HashMap<u32, (usize, u32)> = HashMap::new();
+    let mut sum = 0;
+    for card in cards {
+        let amount = card.winning.intersection(&card.played).count();
+        hm.insert(card.id, (amount, 1));
+    }
+    for i in 1..=hm.len() as u32 {
+        let (amount, copies) = hm
+            .remove(&i)
+            .ok_or_else(|| Error::Invalid(format!("Card {} is missing", i)))?;
+        sum += copies;
+        for _ in 0..copies {
+            for j in (i + 1)..=(i + amount as u32) {
+                if let Some((amount, copies)) = hm.get(&j) {
+                    hm.insert(j, (*amount, *copies + 1));
+                }
+            }
+        }
+    }
+    Ok(Answer::from(sum))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Scratchcards"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,135 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, i64, space1};
+use nom::sequence::{preceded, separated_pair};
+use nom::{IResult, Parser};
+use std::collections::HashMap;
+
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::interval::{Interval, IntervalSet, Piecewise};
+use super::util::parse::{blocks, lines, parse_complete, spaced, triple};
+use super::util::pool;
+
+#[derive(Debug)]
+struct SeedMap {
+    next: String,
+    map: Piecewise<i64>,
+}
+
+fn parse_seeds(input: &str) -> IResult<&str, Vec<i64>> {
+    preceded(tag("seeds: "), spaced(i64))(input)
+}
+
+fn parse_seeds_pt2(input: &str) -> IResult<&str, IntervalSet<i64>> {
+    let (rem, list) =
+        preceded(tag("seeds: "), spaced(separated_pair(i64, space1, i64)))(input)?;
+    Ok((
+        rem,
+        list.into_iter()
+            .map(|(x, y)| Interval::new(x, x + y))
+            .collect(),
+    ))
+}
+
+fn parse_seedmap(input: &str) -> IResult<&str, (String, SeedMap)> {
+    let (rem, (mapin, next)) = separated_pair(alpha1, tag("-to-"), alpha1)(input)?;
+    let (rem, _) = tag(" map:\n")(rem)?;
+    let (rem, v) = lines(triple(i64, " "))(rem)?;
+    let mut map = Piecewise::new();
+    for (dest_start, source_start, range) in v {
+        map.insert(Interval::new(source_start, source_start + range), dest_start - source_start);
+    }
+    Ok((
+        rem,
+        (
+            mapin.to_owned(),
+            SeedMap {
+                next: next.to_owned(),
+                map,
+            },
+        ),
+    ))
+}
+
+fn parse_almanac<'a, S>(
+    input: &'a str,
+    seeds: impl Parser<&'a str, S, nom::error::Error<&'a str>>,
+) -> Result<(S, HashMap<String, SeedMap>), Error> {
+    let (seeds, seedmaps) =
+        parse_complete(input, separated_pair(seeds, tag("\n\n"), blocks(parse_seedmap)))?;
+    Ok((seeds, seedmaps.into_iter().collect()))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let (seeds, seedmaps) = parse_almanac(input, parse_seeds)?;
+    timing::parsed();
+    let maps = chain(&seedmaps)?;
+    let min = seeds
+        .into_iter()
+        .map(|seed| maps.iter().fold(seed, |val, map| map.apply(val)))
+        .min()
+        .ok_or(Error::Invalid("No seeds".to_owned()))?;
+    Ok(Answer::from(min))
+}
+
+/// The maps from seed to location, in the order they apply.
+fn chain(seedmaps: &HashMap<String, SeedMap>) -> Result<Vec<&Piecewise<i64>>, Error> {
+    let mut maps = Vec::new();
+    let mut from = "seed";
+    while from != "location" {
+        let seedmap = seedmaps
+            .get(from)
+            .ok_or_else(|| Error::Invalid(format!("No map from {}", from)))?;
+        if maps.len() == seedmaps.len() {
+            return Err(Error::Invalid("The maps go round in a loop".to_owned()));
+        }
+        maps.push(&seedmap.map);
+        from = &seedmap.next;
+    }
+    Ok(maps)
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let (seedranges, seedmaps) = parse_almanac(input, parse_seeds_pt2)?;
+    timing::parsed();
+    let maps = chain(&seedmaps)?;
+    let ranges: Vec<Interval<i64>> = seedranges.iter().copied().collect();
+    let min = pool::map(&ranges, |&range| {
+        let mut vals = IntervalSet::from(range);
+        for map in &maps {
+            vals = map.apply_set(&vals);
+        }
+        vals.min()
+    })
+    .into_iter()
+    .flatten()
+    .min()
+    .ok_or(Error::Invalid("No seeds".to_owned()))?;
+    Ok(Answer::from(min))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "If You Give A Seed A Fertilizer"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,103 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{space1, u64};
+use nom::sequence::{delimited, pair};
+use nom::IResult;
+
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::parse::{parse_complete, spaced};
+
+fn parse<'a>(what: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<u64>> {
+    delimited(pair(tag(what), space1), spaced(u64), tag("\n"))
+}
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, Error> {
+    let (time, distance) = parse_complete(input, pair(parse("Time:"), parse("Distance:")))?;
+    Ok(time
+        .into_iter()
+        .zip(distance)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let races = parse_input(input)?;
+    timing::parsed();
+    let mut res = 1;
+    for race in races {
+        let success_tries = (0..=race.time).fold(0, |s, hold| {
+            if (race.time - hold) * hold > race.distance {
+                s + 1
+            } else {
+                s
+            }
+        });
+        res *= success_tries;
+    }
+    Ok(Answer::from(res))
+}
+
+fn parse_pt2<'a>(what: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, u64> {
+    delimited(tag(what), u64, tag("\n"))
+}
+
+fn parse_input_pt2(input: &str) -> Result<Race, Error> {
+    let (time, distance) =
+        parse_complete(input, pair(parse_pt2("Time:"), parse_pt2("Distance:")))?;
+    Ok(Race { time, distance })
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let parsedinput: String = input.chars().filter(|&c| c != ' ').collect();
+    let race = parse_input_pt2(&parsedinput)?;
+    timing::parsed();
+    let mut first_success = 0;
+    for hold in 0..=race.time {
+        let dist = (race.time - hold) * hold;
+        if dist > race.distance {
+            first_success = hold;
+            break;
+        }
+    }
+    // found first success, find last
+    let mut last_success = 0;
+    for hold in (0..=race.time).rev() {
+        let dist = (race.time - hold) * hold;
+        if dist > race.distance {
+            last_success = hold;
+            break;
+        }
+    }
+    let res = last_success - first_success + 1;
+    Ok(Answer::from(res))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Wait For It"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
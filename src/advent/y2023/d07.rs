@@ -1,10 +1,15 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use super::{Answer, Error, Solution};
+use super::log::trace;
+use super::timing;
 use super::util;
+use super::util::parse::parse_complete;
 
 use nom::bytes::complete::tag;
-use nom::character::complete::{alphanumeric1, u32};
+use nom::character::complete::{one_of, u32};
+use nom::multi::count;
 use nom::sequence::separated_pair;
 use nom::IResult;
 
@@ -18,9 +23,9 @@ struct Hand {
 }
 
 impl Hand {
-    fn new(hand: Vec<u32>, bid: u32) -> Self {
+    fn new(hand: Vec<u32>, bid: u32) -> Result<Self, Error> {
         if hand.len() != 5 {
-            panic!("Not supposed to receive hands with something other than 5 cards");
+            return Err(Error::Invalid(format!("A hand of {} cards instead of 5", hand.len())));
         }
 
         let mut hm: HashMap<u32, u32> = HashMap::new();
@@ -75,7 +80,7 @@ impl Hand {
             _ => 1,
         };
 
-        Hand { bid, class, class_alt, hand, hand_alt }
+        Ok(Hand { bid, class, class_alt, hand, hand_alt })
     }
 
     fn cmp_alt(&self, other: &Self) -> Ordering {
@@ -121,10 +126,12 @@ impl Ord for Hand {
     }
 }
 
-fn parse_hand(input: &str, joker: bool) -> IResult<&str, Hand> {
-    let (remainder, (str, bid)) = separated_pair(alphanumeric1, tag(" "), u32)(input)?;
-    let hand: Vec<u32> = str
-        .chars()
+/// The five card values of a hand and its bid.
+fn parse_hand(input: &str, joker: bool) -> IResult<&str, (Vec<u32>, u32)> {
+    let (remainder, (cards, bid)) =
+        separated_pair(count(one_of("23456789TJQKA"), 5), tag(" "), u32)(input)?;
+    let hand: Vec<u32> = cards
+        .into_iter()
         .map(|c| {
             if c.is_ascii_digit() {
                 c.to_digit(10).unwrap()
@@ -140,55 +147,72 @@ fn parse_hand(input: &str, joker: bool) -> IResult<&str, Hand> {
             }
         })
         .collect();
-    Ok((remainder, Hand::new(hand, bid)))
+    Ok((remainder, (hand, bid)))
 }
 
-fn parse(input: &str, joker: bool) -> Result<Hand, Box<dyn std::error::Error + '_>> {
-    let (remainder, hand) = parse_hand(input, joker)?;
-    if !remainder.is_empty() {
-        Err(Box::new(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            format!("Remainder remaining after parsing game: {}", remainder),
-        )))
-    } else {
-        Ok(hand)
-    }
+fn parse(input: &str, joker: bool) -> Result<Hand, Error> {
+    let (hand, bid) = parse_complete(input, |i| parse_hand(i, joker))?;
+    Hand::new(hand, bid)
 }
 
-pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let lines = util::parse_in_lines(&path)?;
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let lines = util::parse_in_lines(input);
     let mut v = Vec::new();
-    for line in lines {
-        let hand = parse(&line, false).unwrap();
+    for (idx, line) in lines.enumerate() {
+        let hand = parse(line, false).map_err(|e| e.on_line(idx + 1))?;
         v.push(hand);
     }
+    timing::parsed();
     v.sort();
     let sum = v
         .iter()
         .enumerate()
         .fold(0, |s, (idx, h)| s + h.bid * (idx as u32 + 1));
     for (idx, hand) in v.iter().enumerate() {
-        println!("{} {:?}", idx, hand);
+        trace!("{} {:?}", idx, hand);
     }
-    println!("Sum: {}", sum);
-    Ok(())
+    Ok(Answer::from(sum))
 }
 
-pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let lines = util::parse_in_lines(&path)?;
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let lines = util::parse_in_lines(input);
     let mut v = Vec::new();
-    for line in lines {
-        let hand = parse(&line, true).unwrap();
+    for (idx, line) in lines.enumerate() {
+        let hand = parse(line, true).map_err(|e| e.on_line(idx + 1))?;
         v.push(hand);
     }
+    timing::parsed();
     v.sort_by(Hand::cmp_alt);
     let sum = v
         .iter()
         .enumerate()
         .fold(0, |s, (idx, h)| s + h.bid * (idx as u32 + 1));
     for (idx, hand) in v.iter().enumerate() {
-        println!("{} class {} | {:?}", idx + 1, hand.class_alt, hand.hand);
+        trace!("{} class {} | {:?}", idx + 1, hand.class_alt, hand.hand);
+    }
+    Ok(Answer::from(sum))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Camel Cards"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
     }
-    println!("Sum: {}", sum);
-    Ok(())
 }
@@ -0,0 +1,188 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{alphanumeric1, one_of};
+use nom::combinator::recognize;
+use nom::multi::many1;
+use nom::sequence::{pair, tuple};
+use nom::IResult;
+
+use crate::advent::util::cycle;
+use crate::advent::util::graph::{Graph, Interner, NodeId};
+use crate::advent::util::math::{crt, next_congruent};
+use crate::advent::util::parse::{lines, parse_complete};
+use super::{Answer, Error, Solution};
+use super::log::{trace, verbose};
+use super::timing;
+
+/// Bound on the combinations of __Z nodes tried when lining up the ghosts.
+const MAX_SOLUTIONS: usize = 100_000;
+
+/// The map, each node's left edge added before its right one.
+struct Network<'a> {
+    names: Interner<&'a str>,
+    graph: Graph,
+}
+
+impl Network<'_> {
+    fn node(&self, name: &str) -> Result<NodeId, Error> {
+        self.names
+            .id(name)
+            .ok_or_else(|| Error::Invalid(format!("No node named {}", name)))
+    }
+
+    /// Fails on nodes that are referred to but never defined, or defined twice.
+    fn check(&self) -> Result<(), Error> {
+        for id in 0..self.names.len() {
+            match self.graph.edges(id).len() {
+                2 => {}
+                0 => return Err(Error::Invalid(format!("No node named {}", self.names.name(id)))),
+                _ => return Err(Error::Invalid(format!("Node {} defined twice", self.names.name(id)))),
+            }
+        }
+        Ok(())
+    }
+
+    fn step(&self, node: NodeId, direction: u8) -> NodeId {
+        let edges = self.graph.edges(node);
+        edges[if direction == b'L' { 0 } else { 1 }].0
+    }
+}
+
+fn parse_directions(input: &str) -> IResult<&str, String> {
+    let (rem, ret) = recognize(many1(one_of("LR")))(input)?;
+    let (rem, _) = tag("\n\n")(rem)?;
+    Ok((rem, ret.to_owned()))
+}
+
+fn parse_graph(input: &str) -> IResult<&str, Network<'_>> {
+    let (rem, v) = lines(tuple((
+        alphanumeric1,
+        tag(" = ("),
+        alphanumeric1,
+        tag(", "),
+        alphanumeric1,
+        tag(")"),
+    )))(input)?;
+    let mut names = Interner::new();
+    let mut graph = Graph::directed();
+    for (s, _, l, _, r, _) in v {
+        let id = names.intern(s);
+        let l = names.intern(l);
+        let r = names.intern(r);
+        graph.add_edge(id, l, ());
+        graph.add_edge(id, r, ());
+    }
+    Ok((rem, Network { names, graph }))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+
+    let (directions, graph) = parse_complete(input, pair(parse_directions, parse_graph))?;
+    timing::parsed();
+
+    graph.check()?;
+
+    let mut pos = graph.node("AAA")?;
+    let goal = graph.node("ZZZ")?;
+    // having been on a node at the same point of the directions before, the
+    // walk goes round in a loop without ZZZ
+    let mut seen = vec![false; graph.names.len() * directions.len()];
+    let mut moves = 0;
+    for (idx, c) in directions.bytes().cycle().enumerate() {
+        if pos == goal {
+            moves = idx;
+            break;
+        }
+        let state = pos * directions.len() + idx % directions.len();
+        if seen[state] {
+            return Err(Error::Unsolvable("ZZZ cannot be reached from AAA".to_owned()));
+        }
+        seen[state] = true;
+        trace!("idx {} for {}, going {}", idx, graph.names.name(pos), c as char);
+        pos = graph.step(pos, c);
+    }
+    Ok(Answer::from(moves))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+
+    let (directions, graph) = parse_complete(input, pair(parse_directions, parse_graph))?;
+    timing::parsed();
+
+    graph.check()?;
+    let directions = directions.as_bytes();
+    // a ghost's state is its node and how far through the directions it is
+    let step = |&(node, idx): &(NodeId, usize)| (graph.step(node, directions[idx]), (idx + 1) % directions.len());
+    let is_z = |node: NodeId| graph.names.name(node).ends_with('Z');
+
+    let mut ghosts = Vec::new();
+    for init in (0..graph.names.len()).filter(|&id| graph.names.name(id).ends_with('A')) {
+        let history = cycle::find((init, 0), step);
+        let hits: Vec<usize> = (0..history.states.len())
+            .filter(|&i| is_z(history.states[i].0))
+            .collect();
+        let init = graph.names.name(init);
+        if hits.is_empty() {
+            return Err(Error::Unsolvable(format!("{} never reaches a node ending with Z", init)));
+        }
+        verbose!("{}: {:?}, on __Z after {:?}", init, history.cycle, hits);
+        ghosts.push((history, hits));
+    }
+    if ghosts.is_empty() {
+        return Err(Error::Invalid("No node ends with A".to_owned()));
+    }
+
+    // until every ghost is looping, just look
+    let settled = ghosts.iter().map(|(h, _)| h.cycle.start).max().unwrap_or(0);
+    if let Some(moves) = (1..settled).find(|&n| ghosts.iter().all(|(h, _)| is_z(h.nth(n).0))) {
+        return Ok(Answer::from(moves));
+    }
+    // from then on, pick one __Z node within each ghost's loop and solve for
+    // when they line up
+    let mut solutions = vec![(0, 1)];
+    for (history, hits) in &ghosts {
+        let period = history.cycle.period as i128;
+        solutions = solutions
+            .iter()
+            .flat_map(|&solution| {
+                hits.iter()
+                    .filter(|&&hit| hit >= history.cycle.start)
+                    .filter_map(move |&hit| crt([solution, (hit as i128, period)]))
+            })
+            .collect();
+        if solutions.len() > MAX_SOLUTIONS {
+            return Err(Error::Unsolvable("too many ways for the ghosts to line up".to_owned()));
+        }
+    }
+    let moves = solutions
+        .into_iter()
+        .map(|(offset, period)| next_congruent(settled.max(1) as i128, offset, period))
+        .min()
+        .ok_or(Error::Unsolvable("the ghosts are never all on __Z nodes".to_owned()))?;
+    Ok(Answer::from(moves))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Haunted Wasteland"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
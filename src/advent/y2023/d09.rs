@@ -0,0 +1,66 @@
+use nom::character::complete::i64;
+
+use super::{Answer, Error, Solution};
+use super::log::trace;
+use super::timing;
+use super::util::parse::{lines, parse_complete, spaced};
+use super::util::poly::Newton;
+
+/// Extrapolates `seq` to the value at `index`, one before the start being -1.
+fn extrapolate(seq: &[i64], index: i128) -> Result<i128, Error> {
+    let samples: Vec<i128> = seq.iter().map(|&x| x as i128).collect();
+    let newton = Newton::fit(&samples);
+    trace!("Differences of {:?}: degree {:?}", seq, newton.degree());
+    if newton.degree().is_none() {
+        return Err(Error::Unsolvable(format!("differences of {:?} never reach zero", seq)));
+    }
+    Ok(newton.eval(index))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let v: Vec<Vec<i64>> = parse_complete(input, lines(spaced(i64)))?;
+    timing::parsed();
+
+
+    let mut sum = 0;
+    for seq in &v {
+        sum += extrapolate(seq, seq.len() as i128)?;
+    }
+    Ok(Answer::from(sum))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let v: Vec<Vec<i64>> = parse_complete(input, lines(spaced(i64)))?;
+    timing::parsed();
+    let mut sum = 0;
+    for seq in &v {
+        sum += extrapolate(seq, -1)?;
+    }
+    Ok(Answer::from(sum))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Mirage Maintenance"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,164 @@
+use super::{Answer, Error, Solution};
+use super::log::verbose;
+use super::timing;
+use super::util;
+use super::util::grid::{Direction, Grid};
+
+/// The two directions a pipe connects, if `tile` is a pipe.
+fn connections(tile: char) -> Option<[Direction; 2]> {
+    match tile {
+        '|' => Some([Direction::North, Direction::South]),
+        '-' => Some([Direction::East, Direction::West]),
+        'L' => Some([Direction::North, Direction::East]),
+        'J' => Some([Direction::North, Direction::West]),
+        '7' => Some([Direction::South, Direction::West]),
+        'F' => Some([Direction::South, Direction::East]),
+        _ => None,
+    }
+}
+
+fn get_valid_neighbors(grid: &Grid<char>, start: (usize, usize)) -> Result<Vec<(usize,usize)>, Error> {
+    let valid_paths: Vec<(usize, usize)> = Direction::ALL
+        .into_iter()
+        .filter_map(|dir| {
+            let next = grid.step(start, dir)?;
+            connections(grid[next])?.contains(&dir.opposite()).then_some(next)
+        })
+        .collect();
+    if valid_paths.len() != 2 {
+        return Err(Error::Invalid(format!("{} pipes connect to the start", valid_paths.len())));
+    }
+    Ok(valid_paths)
+}
+
+/// The two cells the pipe at `pos` leads to.
+fn follow(grid: &Grid<char>, pos: (usize, usize)) -> Result<[(usize, usize); 2], Error> {
+    let [a, b] = connections(grid[pos])
+        .ok_or_else(|| Error::Invalid(format!("Reached unexpected {}", grid[pos])))?;
+    let step = |dir| {
+        grid.step(pos, dir)
+            .ok_or_else(|| Error::Invalid(format!("Pipe at {:?} leads off the grid", pos)))
+    };
+    Ok([step(a)?, step(b)?])
+}
+
+/// The cells of the loop through `start`, in order around it from `start`.
+fn walk_loop(grid: &Grid<char>, start: (usize, usize)) -> Result<Vec<(usize, usize)>, Error> {
+    let mut cells = vec![start];
+    let (mut prev, mut cur) = (start, get_valid_neighbors(grid, start)?[0]);
+    while cur != start {
+        cells.push(cur);
+        let [a, b] = follow(grid, cur)?;
+        let next = if a == prev {
+            b
+        } else if b == prev {
+            a
+        } else {
+            let reason = format!("Pipe at {:?} does not lead back to {:?}", cur, prev);
+            return Err(Error::Invalid(reason));
+        };
+        (prev, cur) = (cur, next);
+    }
+    Ok(cells)
+}
+
+const TILES: &str = "|-LJ7F.S";
+
+/// Reads the pipe maze, returning it along with the start.
+fn read_maze(input: &str) -> Result<(Grid<char>, (usize, usize)), Error> {
+    let input = input.trim();
+    let (grid, markers) =
+        Grid::parse_with_markers(input, &['S'], |c| TILES.contains(c).then_some(c))?;
+    Ok((grid, markers.one('S')?))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let (grid, start) = read_maze(input)?;
+    timing::parsed();
+    util::grid::print_grid(&grid, 1);
+
+    // the distance to a cell is the shorter way round the loop from the start
+    let cells = walk_loop(&grid, start)?;
+    let mut weights = grid.map(|_| 0);
+    for (i, &pos) in cells.iter().enumerate() {
+        weights[pos] = i.min(cells.len() - i) as i64;
+    }
+
+    util::grid::print_grid(&weights, 1);
+    let max = weights.iter().max().ok_or(Error::Invalid("Empty grid".to_owned()))?;
+
+    Ok(Answer::from(*max))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let (grid, start) = read_maze(input)?;
+    timing::parsed();
+    let vertices: Vec<(i64, i64)> = walk_loop(&grid, start)?
+        .into_iter()
+        .map(|(r, c)| (r as i64, c as i64))
+        .collect();
+
+    let points = util::math::lattice_points(vertices.as_slice());
+    verbose!("Lattice points: {:?}\nPolygon Count: {}", points, vertices.len());
+
+    Ok(Answer::from(points.interior))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Pipe Maze"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A loop snaking through every cell of an `n` by `n` grid, `n` even, with
+    /// the first column as the way back up to the start in the top left.
+    fn serpentine(n: usize) -> String {
+        let mut rows = Vec::new();
+        for r in 0..n {
+            let row: String = (0..n)
+                .map(|c| match (r, c) {
+                    (0, 0) => 'S',
+                    (0, c) if c == n - 1 => '7',
+                    (r, 0) if r == n - 1 => 'L',
+                    (_, 0) => '|',
+                    (r, c) if r % 2 == 1 && c == n - 1 => 'J',
+                    (r, 1) if r % 2 == 1 && r != n - 1 => 'F',
+                    (r, c) if r % 2 == 0 && r != 0 && c == n - 1 => '7',
+                    (r, 1) if r % 2 == 0 && r != 0 => 'L',
+                    _ => '-',
+                })
+                .collect();
+            rows.push(row);
+        }
+        rows.join("\n")
+    }
+
+    #[test]
+    fn long_loop() {
+        let maze = serpentine(100);
+        assert_eq!(pt1(&maze).unwrap(), Answer::from(5000));
+        assert_eq!(pt2(&maze).unwrap(), Answer::from(0));
+    }
+}
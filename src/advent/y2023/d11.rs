@@ -0,0 +1,129 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Answer, Error, Solution};
+use super::log::trace;
+use super::timing;
+use super::util::grid::Grid;
+
+/// Sums the distances between every pair of galaxies once each empty row and
+/// column has been replaced by `expansion` of them.
+fn sum_distances(input: &str, expansion: usize) -> Result<Answer, Error> {
+    let input = input.trim();
+
+    let mut next_planet = 1;
+    let grid = Grid::parse(input, |c| match c {
+        '#' => {
+            next_planet += 1;
+            Some(next_planet - 1)
+        }
+        '.' => Some(0),
+        _ => None,
+    })?;
+    timing::parsed();
+
+    let expand_rows: Vec<usize> = (0..grid.height())
+        .filter(|&i| grid[i].iter().all(|&p| p == 0))
+        .collect();
+    let expand_cols: Vec<usize> = (0..grid.width())
+        .filter(|&j| grid.column(j).all(|&p| p == 0))
+        .collect();
+
+    let mut rowdistset: HashSet<usize> = HashSet::new();
+    let mut coldistset: HashSet<usize> = HashSet::new();
+
+    for row in expand_rows.iter().rev() {
+        rowdistset.insert(*row);
+    }
+    for col in expand_cols.iter().rev() {
+        coldistset.insert(*col);
+    }
+    let planets = next_planet - 1;
+
+    let mut hm: HashMap<usize, (usize,usize)> = HashMap::new();
+
+    for ((j, i), x) in grid.indexed() {
+        if *x != 0 {
+            hm.insert(*x, (i, j));
+        }
+    }
+
+    let mut pairs = Vec::new();
+    for i in 1..=planets {
+        for j in i + 1..=planets {
+            pairs.push((i, j));
+        }
+    }
+
+    let mut sum = 0;
+
+    for (a, b) in pairs {
+        let missing = |id| Error::Invalid(format!("Galaxy {} is not on the map", id));
+        let (ax, ay) = hm.get(&a).ok_or_else(|| missing(a))?;
+        let (bx, by) = hm.get(&b).ok_or_else(|| missing(b))?;
+        let xrange = if ax < bx { *ax..=*bx } else { *bx..=*ax };
+        let yrange = if ay < by { *ay..=*by } else { *by..=*ay };
+        let mut dist = ax.abs_diff(*bx) + ay.abs_diff(*by);
+        for row in &rowdistset {
+            if yrange.contains(row) {
+                dist += expansion - 1;
+            }
+        }
+        for col in &coldistset {
+            if xrange.contains(col) {
+                dist += expansion - 1;
+            }
+        }
+        sum += dist;
+        trace!("{} -> {}: {}", a, b, dist);
+    }
+
+
+
+    Ok(Answer::from(sum))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    sum_distances(input, 2)
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    sum_distances(input, 1_000_000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Cosmic Expansion"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day11.example.txt");
+
+    #[test]
+    fn sum_distances_example() {
+        assert_eq!(sum_distances(EXAMPLE, 2).unwrap(), Answer::from(374));
+        assert_eq!(sum_distances(EXAMPLE, 10).unwrap(), Answer::from(1030));
+        assert_eq!(sum_distances(EXAMPLE, 100).unwrap(), Answer::from(8410));
+    }
+}
@@ -1,14 +1,19 @@
 use std::collections::HashMap;
 
 use nom::bytes::complete::tag;
-use nom::character::complete::none_of;
+use nom::character::complete::one_of;
 use nom::character::complete::u32;
 use nom::multi::many1;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 use nom::IResult;
 
+use super::{Answer, Error, Solution};
+use super::log::trace;
+use super::timing;
 use super::util;
+use super::util::pool;
+use super::util::parse::parse_complete;
 
 #[derive(Debug)]
 struct Record {
@@ -62,7 +67,7 @@ impl Record {
     }
 
     fn permutations(&self) -> u64 {
-        println!("{} {:?}", self.map.iter().collect::<String>(), self.counts);
+        trace!("{} {:?}", self.map.iter().collect::<String>(), self.counts);
         let brok = self.counts.clone();
         Record::map_permutations(&self.map[..], &brok[..], 0, &mut HashMap::new())
     }
@@ -70,7 +75,7 @@ impl Record {
 
 fn parse_line(input: &str) -> IResult<&str, Record> {
     let (rem, (map, v)) = separated_pair(
-        many1(none_of(" ")),
+        many1(one_of(".#?")),
         tag(" "),
         separated_list1(tag(","), u32),
     )(input)?;
@@ -83,27 +88,29 @@ fn parse_line(input: &str) -> IResult<&str, Record> {
     ))
 }
 
-pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let lines = util::parse_in_lines(&path)?;
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let lines = util::parse_in_lines(input);
 
     let records: Vec<Record> = lines
-        .into_iter()
-        .map(|e| parse_line(&e).unwrap().1)
-        .collect();
+        .enumerate()
+        .map(|(idx, e)| parse_complete(e, parse_line).map_err(|err| err.on_line(idx + 1)))
+        .collect::<Result<_, _>>()?;
+    timing::parsed();
 
-    let sum: u64 = records.iter().map(|r| r.permutations()).sum();
+    let sum: u64 = pool::map(&records, Record::permutations).into_iter().sum();
 
-    println!("Sum of permutations: {}", sum);
 
-    Ok(())
+    Ok(Answer::from(sum))
 }
 
-pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let lines = util::parse_in_lines(&path)?;
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let lines = util::parse_in_lines(input);
 
     let records: Vec<Record> = lines
+        .enumerate()
+        .map(|(idx, e)| parse_complete(e, parse_line).map_err(|err| err.on_line(idx + 1)))
+        .collect::<Result<Vec<_>, _>>()?
         .into_iter()
-        .map(|e| parse_line(&e).unwrap().1)
         .map(|r| {
             let mut map = r.map.clone();
             let mut counts = r.counts.clone();
@@ -118,10 +125,69 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
             }
         })
         .collect();
+    timing::parsed();
+
+    let sum: u64 = pool::map(&records, Record::permutations).into_iter().sum();
+
+
+    Ok(Answer::from(sum))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Hot Springs"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
 
-    let sum: u64 = records.iter().map(|r| r.permutations()).sum();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Sum of permutations: {}", sum);
+    fn permutations(map: &str, counts: &[usize]) -> u64 {
+        let map: Vec<char> = map.chars().collect();
+        Record::map_permutations(&map, counts, 0, &mut HashMap::new())
+    }
+
+    #[test]
+    fn map_permutations_example_rows() {
+        assert_eq!(permutations("???.###", &[1, 1, 3]), 1);
+        assert_eq!(permutations(".??..??...?##.", &[1, 1, 3]), 4);
+        assert_eq!(permutations("?#?#?#?#?#?#?#?", &[1, 3, 1, 6]), 1);
+        assert_eq!(permutations("????.#...#...", &[4, 1, 1]), 1);
+        assert_eq!(permutations("????.######..#####.", &[1, 6, 5]), 4);
+        assert_eq!(permutations("?###????????", &[3, 2, 1]), 10);
+    }
 
-    Ok(())
+    #[test]
+    fn map_permutations_unfolded() {
+        let unfolded = ["?###????????"; 5].join("?");
+        assert_eq!(permutations(&unfolded, &[3, 2, 1].repeat(5)), 506250);
+    }
+
+    #[test]
+    fn map_permutations_edges() {
+        assert_eq!(permutations("", &[]), 1);
+        assert_eq!(permutations("...", &[]), 1);
+        assert_eq!(permutations("###", &[2]), 0);
+        assert_eq!(permutations("#.#", &[1]), 0);
+        assert_eq!(permutations("???", &[1]), 3);
+    }
 }
@@ -1,9 +1,8 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::one_of,
-    multi::{many1, separated_list1},
-    IResult,
-};
+use nom::{character::complete::one_of, multi::many1, IResult};
+
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::parse::{blocks, lines, parse_complete};
 
 #[derive(Debug)]
 struct Grid {
@@ -12,7 +11,7 @@ struct Grid {
 }
 
 fn parse_grid(input: &str) -> IResult<&str, Grid> {
-    let (rem, map) = separated_list1(tag("\n"), many1(one_of("#.")))(input)?;
+    let (rem, map) = lines(many1(one_of("#.")))(input)?;
     let rows = map
         .iter()
         .map(|v| {
@@ -35,10 +34,6 @@ fn parse_grid(input: &str) -> IResult<&str, Grid> {
     Ok((rem, Grid { columns, rows }))
 }
 
-fn parse_maps(input: &str) -> IResult<&str, Vec<Grid>> {
-    separated_list1(tag("\n\n"), parse_grid)(input)
-}
-
 fn find_horizon(vec: &[u64], smudges: u32) -> Option<usize> {
     let mut horizon = None;
     let mut griter = vec.iter().enumerate().peekable();
@@ -59,13 +54,11 @@ fn find_horizon(vec: &[u64], smudges: u32) -> Option<usize> {
     horizon
 }
 
-pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
 
-    let (rem, grids) = parse_maps(&input).unwrap();
-    if !rem.is_empty() {
-        panic!("Bad input, remainder: {}", rem);
-    }
+    let grids = parse_complete(input, blocks(parse_grid))?;
+    timing::parsed();
     let mut sum = 0;
     for grid in grids {
         if let Some(horizon) = find_horizon(&grid.columns, 0) {
@@ -74,17 +67,14 @@ pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
             sum += 100 * horizon;
         }
     }
-    println!("Sum {}", sum);
-    Ok(())
+    Ok(Answer::from(sum))
 }
 
-pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
 
-    let (rem, grids) = parse_maps(&input).unwrap();
-    if !rem.is_empty() {
-        panic!("Bad input, remainder: {}", rem);
-    }
+    let grids = parse_complete(input, blocks(parse_grid))?;
+    timing::parsed();
     let mut sum = 0;
     for grid in grids {
         if let Some(horizon) = find_horizon(&grid.columns, 1) {
@@ -93,6 +83,29 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
             sum += 100 * horizon;
         }
     }
-    println!("Sum {}", sum);
-    Ok(())
+    Ok(Answer::from(sum))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Point of Incidence"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
 }
@@ -0,0 +1,159 @@
+use super::{Answer, Error, Solution};
+use super::log::trace;
+use super::timing;
+use super::util;
+use super::util::grid::{Direction, Grid};
+
+fn slide(grid: &mut Grid<char>, direction: Direction) {
+    match direction {
+        Direction::North => {
+            for j in 0..grid.width() {
+                for i in 0..grid.height() {
+                    if grid[i][j] != '.' {
+                        continue;
+                    }
+                    let mut nextpos = None;
+                    for ti in i..grid.height() {
+                        if grid[ti][j] != '.' {
+                            nextpos = Some(ti);
+                            break;
+                        }
+                    }
+                    if let Some(pos) = nextpos {
+                        if grid[pos][j] == 'O' {
+                            grid[i][j] = 'O';
+                            grid[pos][j] = '.';
+                        }
+                    }
+                }
+            }
+        },
+        Direction::West => {
+            for i in 0..grid.height() {
+                for j in 0..grid.width() {
+                    if grid[i][j] != '.' {
+                        continue;
+                    }
+                    let mut nextpos = None;
+                    for tj in j..grid.width() {
+                        if grid[i][tj] != '.' {
+                            nextpos = Some(tj);
+                            break;
+                        }
+                    }
+                    if let Some(pos) = nextpos {
+                        if grid[i][pos] == 'O' {
+                            grid[i][j] = 'O';
+                            grid[i][pos] = '.';
+                        }
+                    }
+                }
+            }
+        }
+        Direction::South => {
+            for j in (0..grid.width()).rev() {
+                for i in (0..grid.height()).rev() {
+                    if grid[i][j] != '.' {
+                        continue;
+                    }
+                    let mut nextpos = None;
+                    for ti in (0..i).rev() {
+                        if grid[ti][j] != '.' {
+                            nextpos = Some(ti);
+                            break;
+                        }
+                    }
+                    if let Some(pos) = nextpos {
+                        if grid[pos][j] == 'O' {
+                            grid[i][j] = 'O';
+                            grid[pos][j] = '.';
+                        }
+                    }
+                }
+            }
+        }
+        Direction::East => {
+            for i in (0..grid.height()).rev() {
+                for j in (0..grid.width()).rev() {
+                    if grid[i][j] != '.' {
+                        continue;
+                    }
+                    let mut nextpos = None;
+                    for tj in (0..j).rev() {
+                        if grid[i][tj] != '.' {
+                            nextpos = Some(tj);
+                            break;
+                        }
+                    }
+                    if let Some(pos) = nextpos {
+                        if grid[i][pos] == 'O' {
+                            grid[i][j] = 'O';
+                            grid[i][pos] = '.';
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn calc_load(grid: &Grid<char>) -> usize {
+    grid.indexed()
+        .filter(|(_, &c)| c == 'O')
+        .map(|((i, _), _)| grid.height() - i)
+        .sum()
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let mut grid = Grid::parse(input, |c| "O#.".contains(c).then_some(c))?;
+    timing::parsed();
+    util::grid::print_grid(&grid, 2);
+    slide(&mut grid, Direction::North);
+    trace!("Afterwards:");
+    util::grid::print_grid(&grid, 2);
+    let load = calc_load(&grid);
+    Ok(Answer::from(load))
+}
+
+fn spin(grid: &Grid<char>) -> Grid<char> {
+    let mut grid = grid.clone();
+    for direction in [Direction::North, Direction::West, Direction::South, Direction::East] {
+        slide(&mut grid, direction);
+    }
+    grid
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let grid = Grid::parse(input, |c| "O#.".contains(c).then_some(c))?;
+    timing::parsed();
+
+    util::grid::print_grid(&grid, 2);
+    let grid = util::cycle::nth(grid, spin, 1_000_000_000);
+    Ok(Answer::from(calc_load(&grid)))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Parabolic Reflector Dish"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
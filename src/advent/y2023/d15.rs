@@ -1,27 +1,31 @@
 use nom::bytes::complete::tag;
-use nom::character::complete::none_of;
+use nom::character::complete::{alpha1, none_of, one_of, u32};
 use nom::multi::many1;
 use nom::multi::separated_list1;
 use nom::sequence::pair;
 use nom::IResult;
 
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::parse::parse_complete;
+
 fn parse_input(input: &str) -> IResult<&str, Vec<String>> {
     let (rem, v) = separated_list1(tag(","), many1(none_of(",")))(input)?;
     Ok((rem, v.iter().map(|vc| vc.iter().collect()).collect()))
 }
 
-pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
 
-    let (_, sequences) = parse_input(&input).unwrap();
+    let sequences = parse_complete(input, parse_input)?;
+    timing::parsed();
 
     let sum: u64 = sequences
         .iter()
         .map(|s| s.chars().fold(0, |s, c| ((s + c as u64) * 17) % 256))
         .sum();
 
-    println!("Sum of hashes: {}", sum);
-    Ok(())
+    Ok(Answer::from(sum))
 }
 
 struct Instr {
@@ -30,30 +34,28 @@ struct Instr {
     target: u32,
 }
 
+fn parse_instr(input: &str) -> IResult<&str, Instr> {
+    let (rem, (label, op)) = pair(alpha1, one_of("=-"))(input)?;
+    let (rem, target) = if op == '=' { u32(rem)? } else { (rem, 0) };
+    Ok((
+        rem,
+        Instr {
+            label: label.to_owned(),
+            op,
+            target,
+        },
+    ))
+}
+
 fn parse_input_pt2(input: &str) -> IResult<&str, Vec<Instr>> {
-    let (rem, v) =
-        separated_list1(tag(","), pair(many1(none_of("=-")), many1(none_of(","))))(input)?;
-    let v = v
-        .iter()
-        .map(|(l, o)| {
-            let mut target = 0;
-            if o[0] == '=' {
-                target = o[1].to_digit(10).unwrap();
-            }
-            Instr {
-                label: l.iter().collect::<String>(),
-                op: o[0],
-                target,
-            }
-        })
-        .collect();
-    Ok((rem, v))
+    separated_list1(tag(","), parse_instr)(input)
 }
 
-pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
 
-    let (_, sequences) = parse_input_pt2(&input).unwrap();
+    let sequences = parse_complete(input, parse_input_pt2)?;
+    timing::parsed();
 
     let mut boxes: Vec<Vec<(String, u32)>> = Vec::new();
     for _ in 0..256 {
@@ -82,6 +84,29 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
         }).sum::<usize>()
     }).sum();
 
-    println!("Sum of hashes: {}", sum);
-    Ok(())
+    Ok(Answer::from(sum))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Lens Library"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
 }
@@ -0,0 +1,154 @@
+use std::fmt::Display;
+
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util;
+use super::util::pool;
+use super::util::grid::{Direction, Grid};
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    struct LightDirections: u8 {
+        const NORTH = 0b0001;
+        const WEST  = 0b0100;
+        const SOUTH = 0b0010;
+        const EAST  = 0b1000;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Point {
+    dirs: LightDirections,
+    tile: char,
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = if self.dirs.is_empty() { self.tile } else { '#' };
+        write!(f, "{}", c)
+    }
+}
+
+fn energize(grid: &mut Grid<Point>, (i, j): (usize, usize), to: LightDirections) -> u64 {
+    let next_dirs = match grid[i][j].tile {
+        '.' => to,
+        '\\' => match to {
+            LightDirections::NORTH => LightDirections::WEST,
+            LightDirections::EAST => LightDirections::SOUTH,
+            LightDirections::SOUTH => LightDirections::EAST,
+            LightDirections::WEST => LightDirections::NORTH,
+            _ => unreachable!("Caught from that does not match"),
+        },
+        '/' => match to {
+            LightDirections::NORTH => LightDirections::EAST,
+            LightDirections::WEST => LightDirections::SOUTH,
+            LightDirections::SOUTH => LightDirections::WEST,
+            LightDirections::EAST => LightDirections::NORTH,
+            _ => unreachable!("Caught from that does not match"),
+        },
+        '|' => match to {
+            LightDirections::EAST | LightDirections::WEST => {
+                LightDirections::NORTH | LightDirections::SOUTH
+            }
+            _ => to,
+        },
+        '-' => match to {
+            LightDirections::NORTH | LightDirections::SOUTH => {
+                LightDirections::EAST | LightDirections::WEST
+            }
+            _ => to,
+        },
+        _ => {
+            unreachable!("No grid match")
+        }
+    };
+    if grid[i][j].dirs.contains(next_dirs) {
+        // no change here, already accounted for
+        0
+    } else {
+        let mut ret = if grid[i][j].dirs == LightDirections::empty() {
+            1
+        } else {
+            0
+        };
+        grid[i][j].dirs |= next_dirs;
+        for light in next_dirs.iter() {
+            let dir = match light {
+                LightDirections::NORTH => Direction::North,
+                LightDirections::SOUTH => Direction::South,
+                LightDirections::EAST => Direction::East,
+                LightDirections::WEST => Direction::West,
+                _ => {
+                    unreachable!("No grid match")
+                }
+            };
+            if let Some(next) = grid.step((i, j), dir) {
+                ret += energize(grid, next, light);
+            }
+        }
+        ret
+    }
+}
+
+fn read_grid(input: &str) -> Result<Grid<Point>, Error> {
+    let input = input.trim();
+    Grid::parse(input, |tile| {
+        ".\\/|-".contains(tile).then_some(Point {
+            dirs: LightDirections::empty(),
+            tile,
+        })
+    })
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let mut grid = read_grid(input)?;
+    timing::parsed();
+    util::grid::print_grid(&grid, 2);
+    Ok(Answer::from(energize(&mut grid, (0, 0), LightDirections::EAST)))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let grid = read_grid(input)?;
+    timing::parsed();
+    util::grid::print_grid(&grid, 2);
+
+    let (height, width) = (grid.height(), grid.width());
+    let mut entries = Vec::new();
+    for i in 0..height {
+        entries.push(((i, 0), LightDirections::EAST));
+        entries.push(((i, width - 1), LightDirections::WEST));
+    }
+    for j in 0..width {
+        entries.push(((0, j), LightDirections::SOUTH));
+        entries.push(((height - 1, j), LightDirections::NORTH));
+    }
+    let max_energized = pool::map(&entries, |&(pos, to)| energize(&mut grid.clone(), pos, to))
+        .into_iter()
+        .max()
+        .unwrap_or(0);
+    Ok(Answer::from(max_energized))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "The Floor Will Be Lava"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,80 @@
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::grid::{Direction, Grid};
+use super::util::path::astar;
+
+/// Where the crucible is, which way it last moved and how many blocks in a
+/// row it has moved that way.
+type State = ((usize, usize), Direction, usize);
+
+fn shortest_path(grid: &Grid<u32>, minstraight: usize, maxstraight: usize) -> Result<u32, Error> {
+    let goal = (grid.height() - 1, grid.width() - 1);
+    let starts = [((0, 0), Direction::East, 0), ((0, 0), Direction::South, 0)];
+    let successors = |&(pos, dir, straight_for): &State| {
+        let mut turns = Vec::new();
+        if straight_for >= minstraight {
+            turns.push((dir.turn_left(), 1));
+            turns.push((dir.turn_right(), 1));
+        }
+        if straight_for < maxstraight {
+            turns.push((dir, straight_for + 1));
+        }
+        turns.into_iter().filter_map(move |(dir, straight_for)| {
+            let next = grid.step(pos, dir)?;
+            Some(((next, dir, straight_for), grid[next]))
+        })
+    };
+    // each remaining block costs at least the cheapest one, so this never overshoots
+    let cheapest = grid.iter().copied().min().unwrap_or(0);
+    let heuristic = |&((row, col), _, _): &State| (goal.0 - row + goal.1 - col) as u32 * cheapest;
+    let is_goal = |&(pos, _, straight_for): &State| pos == goal && straight_for >= minstraight;
+
+    astar(starts, successors, heuristic, is_goal)
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::Unsolvable("the crucible cannot reach the factory".to_owned()))
+}
+
+fn read_grid(input: &str) -> Result<Grid<u32>, Error> {
+    let input = input.trim();
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let grid = read_grid(input)?;
+    timing::parsed();
+    let distance = shortest_path(&grid, 0, 3)?;
+
+    Ok(Answer::from(distance))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let grid = read_grid(input)?;
+    timing::parsed();
+    let distance = shortest_path(&grid, 4, 10)?;
+
+    Ok(Answer::from(distance))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Clumsy Crucible"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
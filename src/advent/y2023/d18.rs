@@ -0,0 +1,106 @@
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util;
+use super::util::parse::parse_complete;
+
+use nom::bytes::complete::tag;
+use nom::bytes::complete::take_while_m_n;
+use nom::character::complete::{one_of, i64};
+use nom::sequence::{delimited, separated_pair};
+use nom::IResult;
+
+#[derive(Debug)]
+struct Move {
+    dir: char,
+    amt: i64,
+    color: String,
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Move> {
+    let (remainder, ((dir, amt), colorstr)) = separated_pair(
+        separated_pair(one_of("UDLR"), tag(" "), i64),
+        tag(" "),
+        delimited(
+            tag("(#"),
+            take_while_m_n(6, 6, |c: char| c.is_ascii_hexdigit()),
+            tag(")"),
+        ),
+    )(input)?;
+    Ok((remainder, Move { dir, amt, color: colorstr.to_owned() }))
+}
+
+fn step(dir: char) -> (i64, i64) {
+    match dir {
+        'L' => (-1, 0),
+        'R' => (1, 0),
+        'U' => (0, -1),
+        'D' => (0, 1),
+        _ => unreachable!(),
+    }
+}
+
+/// Cubic meters of lava the lagoon dug by `moves` holds, trench included.
+fn lagoon(moves: &[Move]) -> i128 {
+    let vertices = util::math::trace(moves.iter().map(|m| (step(m.dir), m.amt)));
+    util::math::lattice_points(&vertices).total()
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let lines = util::parse_in_lines(input);
+    let mut moves = Vec::new();
+    for (idx, line) in lines.enumerate() {
+        moves.push(parse_complete(line, parse_moves).map_err(|e| e.on_line(idx + 1))?);
+    }
+    timing::parsed();
+    Ok(Answer::from(lagoon(&moves)))
+}
+
+fn pt2extract(m: Move) -> Result<Move, Error> {
+    Ok(Move {
+        color: "".to_owned(),
+        dir: match &m.color[5..] {
+            "0" => 'R',
+            "1" => 'D',
+            "2" => 'L',
+            "3" => 'U',
+            x => return Err(Error::Invalid(format!("No direction encoded as {}", x))),
+        },
+        // six hex digits are guaranteed by the parser
+        amt: i64::from_str_radix(&m.color[..5], 16).unwrap(),
+    })
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let lines = util::parse_in_lines(input);
+    let mut moves = Vec::new();
+    for (idx, line) in lines.enumerate() {
+        let m = parse_complete(line, parse_moves).map_err(|e| e.on_line(idx + 1))?;
+        moves.push(pt2extract(m)?);
+    }
+    timing::parsed();
+    Ok(Answer::from(lagoon(&moves)))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "Lavaduct Lagoon"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, i64, one_of};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, separated_pair, tuple};
+use nom::IResult;
+
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::interval::Interval;
+use super::util::parse::{lines, parse_complete};
+
+#[derive(Debug)]
+enum Op {
+    GT,
+    LT,
+}
+
+#[derive(Debug,PartialEq,Eq,Hash,Clone,Copy)]
+enum Var {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    condition: Option<(Var, Op, i64)>,
+    target: &'a str,
+}
+
+fn parse_op(input: &str) -> IResult<&str, Op> {
+    let (rem, varc) = one_of("<>")(input)?;
+    match varc {
+        '>' => Ok((rem, Op::GT)),
+        '<' => Ok((rem, Op::LT)),
+        _ => unreachable!(),
+    }
+}
+
+fn parse_var(input: &str) -> IResult<&str, Var> {
+    let (rem, varc) = one_of("xmas")(input)?;
+    match varc {
+        'x' => Ok((rem, Var::X)),
+        'm' => Ok((rem, Var::M)),
+        'a' => Ok((rem, Var::A)),
+        's' => Ok((rem, Var::S)),
+        _ => unreachable!(),
+    }
+}
+
+fn parse_rule(input: &str) -> IResult<&str, Rule> {
+    let mut i = input;
+    let mut condition = None;
+    if let Ok((rem, (var, op, target, _))) = tuple((parse_var, parse_op, i64, tag(":")))(i) {
+        i = rem;
+        condition = Some((var, op, target));
+    }
+    let (rem, target) = alpha1(i)?;
+    Ok((rem, Rule { condition, target }))
+}
+
+fn parse_workflow(input: &str) -> IResult<&str, (&str, Vec<Rule>)> {
+    let (remainder, (name, v)) = tuple((
+        alpha1,
+        delimited(tag("{"), separated_list1(tag(","), parse_rule), tag("}")),
+    ))(input)?;
+    Ok((remainder, (name, v)))
+}
+
+fn parse_workflows(input: &str) -> IResult<&str, HashMap<&str, Vec<Rule>>> {
+    let (rem, v) = lines(parse_workflow)(input)?;
+    Ok((rem, v.into_iter().collect()))
+}
+
+#[derive(Debug)]
+struct Part {
+    x: i64,
+    m: i64,
+    a: i64,
+    s: i64,
+}
+
+fn parse_parts(input: &str) -> IResult<&str, Vec<Part>> {
+    let (rem, v) = lines(tuple((
+        tag("{x="),
+        i64,
+        tag(",m="),
+        i64,
+        tag(",a="),
+        i64,
+        tag(",s="),
+        i64,
+        tag("}"),
+    )))(input)?;
+    Ok((
+        rem,
+        v.into_iter()
+            .map(|(_, x, _, m, _, a, _, s, _)| Part { x, m, a, s })
+            .collect(),
+    ))
+}
+
+type Input<'a> = (HashMap<&'a str, Vec<Rule<'a>>>, Vec<Part>);
+
+fn parse_input(input: &str) -> Result<Input<'_>, Error> {
+    parse_complete(input, separated_pair(parse_workflows, tag("\n\n"), parse_parts))
+}
+
+fn get_workflow<'a, 'b>(workflows: &'a HashMap<&str, Vec<Rule<'b>>>, name: &str) -> Result<&'a Vec<Rule<'b>>, Error> {
+    workflows
+        .get(name)
+        .ok_or_else(|| Error::Invalid(format!("No workflow named {}", name)))
+}
+
+fn apply_workflow(workflows: &HashMap<&str, Vec<Rule>>, part: &Part, wf: &[Rule<'_>]) -> Result<i64, Error> {
+    for rule in wf {
+        if let Some((var, op, check)) = &rule.condition {
+            let val = match var {
+                Var::X => part.x,
+                Var::M => part.m,
+                Var::A => part.a,
+                Var::S => part.s,
+            };
+            let pass = match op {
+                Op::GT => val > *check,
+                Op::LT => val < *check,
+            };
+            if !pass {
+                continue;
+            }
+        }
+        return match rule.target {
+            "A" => Ok(part.x + part.m + part.a + part.s),
+            "R" => Ok(0),
+            x => apply_workflow(workflows, part, get_workflow(workflows, x)?),
+        };
+    }
+    Ok(0)
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let (workflows, parts) = parse_input(input)?;
+    timing::parsed();
+
+    let mut sum = 0;
+    for part in parts {
+        let wf = get_workflow(&workflows, "in")?;
+        sum += apply_workflow(&workflows, &part, wf)?;
+    }
+    Ok(Answer::from(sum))
+}
+
+#[derive(Debug)]
+struct PartRange {
+    hm: HashMap<Var, Interval<i64>>,
+}
+
+fn apply_workflow_ranges(workflows: &HashMap<&str, Vec<Rule>>, mut pr: PartRange, wf: &[Rule]) -> Result<i64, Error> {
+    let mut sum = 0;
+    for rule in wf {
+        let Some((var, op, check)) = &rule.condition else {
+            return Ok(sum + send_ranges(workflows, pr, rule.target)?);
+        };
+        let r = pr.hm[var];
+        // [1, 2, 3, 4] - >3 -> [4] passes, [1, 2, 3] carries on
+        // [1, 2, 3, 4] - <3 -> [1, 2] passes, [3, 4] carries on
+        let (pass, fail) = match op {
+            Op::GT => {
+                let (low, high) = r.split_at(*check + 1);
+                (high, low)
+            }
+            Op::LT => r.split_at(*check),
+        };
+        let mut hm_pass = pr.hm.clone();
+        hm_pass.insert(*var, pass);
+        sum += send_ranges(workflows, PartRange { hm: hm_pass }, rule.target)?;
+        pr.hm.insert(*var, fail);
+    }
+    Ok(sum)
+}
+
+fn send_ranges(workflows: &HashMap<&str, Vec<Rule>>, pr: PartRange, target: &str) -> Result<i64, Error> {
+    if pr.hm.values().any(|r| r.is_empty()) {
+        return Ok(0);
+    }
+    match target {
+        "A" => Ok(pr.hm.values().map(|r| r.len()).product()),
+        "R" => Ok(0),
+        x => apply_workflow_ranges(workflows, pr, get_workflow(workflows, x)?),
+    }
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let (workflows, _parts) = parse_input(input)?;
+    timing::parsed();
+
+    let ratings = Interval::inclusive(1, 4000);
+    let pr = PartRange {
+        hm: HashMap::from([
+            (Var::X, ratings),
+            (Var::M, ratings),
+            (Var::A, ratings),
+            (Var::S, ratings),
+        ]),
+    };
+    let sum = apply_workflow_ranges(&workflows, pr, get_workflow(&workflows, "in")?)?;
+    Ok(Answer::from(sum))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Aplenty"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Display};
+
+use nom::character::complete::{alpha1, none_of};
+use nom::combinator::recognize;
+use nom::multi::many1;
+use nom::IResult;
+
+use super::{Answer, Error, Solution};
+use super::log::{trace, verbose};
+use super::timing;
+use super::util;
+use super::util::graph::{Graph, Interner, NodeId};
+use super::util::parse::{edges, lines, parse_complete};
+
+/// Bound on the button presses spent waiting for the inputs of rx's feeder
+/// to fire.
+const MAX_PRESSES: u64 = 100_000;
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq)]
+enum Signal {
+    High,
+    Low,
+}
+
+impl Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", if *self == Signal::Low { "low" } else { "high" })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Eq)]
+struct Module {
+    kind: &'static str,
+    on: bool,
+    inputstate: HashMap<NodeId, Signal>,
+}
+
+impl Module {
+    /// The signal this module sends to all of its outputs, if any.
+    fn process(&mut self, from: NodeId, signal: Signal) -> Option<Signal> {
+        match self.kind {
+            "flipflop" => {
+                if signal == Signal::Low {
+                    self.on = !self.on;
+                    Some(if self.on { Signal::High } else { Signal::Low })
+                } else {
+                    None
+                }
+            }
+            "broadcaster" => Some(signal),
+            "conjunction" => {
+                self.inputstate.insert(from, signal);
+                let send_signal = self.inputstate.values().fold(Signal::Low, |s, sig| {
+                    if *sig == Signal::Low {
+                        Signal::High
+                    } else {
+                        s
+                    }
+                });
+                Some(send_signal)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The modules indexed by id, with the wiring between them as a graph. Pure
+/// outputs such as `rx` have no module.
+struct Machine<'a> {
+    names: Interner<&'a str>,
+    wires: Graph,
+    modules: Vec<Option<Module>>,
+}
+
+impl Machine<'_> {
+    fn id(&self, name: &str) -> Result<NodeId, Error> {
+        self.names
+            .id(name)
+            .ok_or_else(|| Error::Invalid(format!("No module named {}", name)))
+    }
+
+    /// Presses the button once, handing every pulse sent to `observe`.
+    fn press(&mut self, button: NodeId, broadcaster: NodeId, mut observe: impl FnMut(NodeId, NodeId, Signal)) {
+        let mut queue = VecDeque::new();
+        queue.push_back((button, broadcaster, Signal::Low));
+        while let Some((from, to, signal)) = queue.pop_front() {
+            observe(from, to, signal);
+            let Some(Some(module)) = self.modules.get_mut(to) else {
+                continue;
+            };
+            if let Some(next_signal) = module.process(from, signal) {
+                for next_to in self.wires.neighbors(to) {
+                    queue.push_back((to, next_to, next_signal));
+                }
+            }
+        }
+    }
+}
+
+fn parse_module(input: &str) -> IResult<&str, (char, &str, Vec<&str>)> {
+    let (rem, (modchars, outputs)) =
+        edges(recognize(many1(none_of(" "))), " -> ", alpha1, ", ")(input)?;
+    Ok(match modchars.chars().next() {
+        Some(kind @ ('%' | '&')) => (rem, (kind, &modchars[1..], outputs)),
+        _ => (rem, (' ', modchars, outputs)),
+    })
+}
+
+fn parse_input(input: &str) -> IResult<&str, Machine<'_>> {
+    let (rem, modules) = lines(parse_module)(input)?;
+    let mut names = Interner::new();
+    names.intern("button");
+    let mut wires = Graph::directed();
+    let mut kinds = Vec::new();
+    for (kind, name, outputs) in modules {
+        let id = names.intern(name);
+        kinds.push((id, kind));
+        for output in outputs {
+            wires.add_edge(id, names.intern(output), ());
+        }
+    }
+    let mut mods: Vec<Option<Module>> = vec![None; names.len()];
+    for (id, kind) in kinds {
+        mods[id] = Some(Module {
+            kind: match kind {
+                '%' => "flipflop",
+                '&' => "conjunction",
+                _ => "broadcaster",
+            },
+            on: false,
+            inputstate: HashMap::new(),
+        });
+    }
+    // initialize conjunctions
+    for from in 0..wires.len() {
+        for to in wires.neighbors(from) {
+            if let Some(cj) = mods[to].as_mut().filter(|m| m.kind == "conjunction") {
+                cj.inputstate.insert(from, Signal::Low);
+            }
+        }
+    }
+
+    Ok((rem, Machine { names, wires, modules: mods }))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let mut machine = parse_complete(input, parse_input)?;
+    timing::parsed();
+    let (button, broadcaster) = (machine.id("button")?, machine.id("broadcaster")?);
+    let names = machine.names.clone();
+    let (mut lowcount, mut highcount) = (0, 0);
+    for _ in 0..1000 {
+        machine.press(button, broadcaster, |from, to, signal| {
+            trace!("{} -{}> {}", names.name(from), signal, names.name(to));
+            match signal {
+                Signal::Low => lowcount += 1,
+                Signal::High => highcount += 1,
+            };
+        });
+    }
+    verbose!("Lowcount {}\nHighcount {}", lowcount, highcount);
+    Ok(Answer::from(lowcount * highcount))
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let mut machine = parse_complete(input, parse_input)?;
+    timing::parsed();
+    let (button, broadcaster, rx) = (machine.id("button")?, machine.id("broadcaster")?, machine.id("rx")?);
+    //based on input 4 independent pules arrive on vr, which is the input of rx
+    let vr = (0..machine.wires.len())
+        .find(|&id| machine.wires.neighbors(id).any(|o| o == rx))
+        .ok_or(Error::Unsolvable("no module feeds rx".to_owned()))?;
+    let inputs = match &machine.modules[vr] {
+        Some(m) if m.kind == "conjunction" => m.inputstate.keys().copied().collect::<Vec<_>>(),
+        _ => return Err(Error::Unsolvable(format!("{} is not a conjunction", machine.names.name(vr)))),
+    };
+    // the presses on which each input of vr first sent a high pulse, twice.
+    // util::cycle does not fit: the pulse fires mid-press, while the machine's
+    // state after a press only repeats once every input has come round at once
+    let mut cycle_map: HashMap<NodeId, Vec<u64>> = inputs.into_iter().map(|e| (e, Vec::new())).collect();
+    if cycle_map.is_empty() {
+        return Err(Error::Unsolvable(format!("{} has no inputs", machine.names.name(vr))));
+    }
+    for i in 1..=MAX_PRESSES {
+        machine.press(button, broadcaster, |from, _, signal| {
+            if let Some(presses) = cycle_map.get_mut(&from) {
+                if signal == Signal::High && presses.len() < 2 && presses.last() != Some(&i) {
+                    presses.push(i);
+                }
+            }
+        });
+        if cycle_map.values().all(|p| p.len() == 2) {
+            break;
+        }
+    }
+    if let Some((&id, _)) = cycle_map.iter().find(|(_, p)| p.len() < 2) {
+        return Err(Error::Unsolvable(format!(
+            "{} did not send two high pulses in {} presses",
+            machine.names.name(id),
+            MAX_PRESSES
+        )));
+    }
+    for (id, presses) in &cycle_map {
+        verbose!("{}: {:?}", machine.names.name(*id), presses);
+    }
+    // assume each input keeps firing every (second - first) presses
+    let settled = cycle_map.values().map(|p| p[0]).max().unwrap_or(0) as i128;
+    let congruences = cycle_map
+        .values()
+        .map(|p| (p[0] as i128, (p[1] - p[0]) as i128));
+    let (offset, period) = util::math::crt(congruences)
+        .ok_or(Error::Unsolvable("the inputs of rx never fire together".to_owned()))?;
+    Ok(Answer::from(util::math::next_congruent(settled, offset, period)))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Pulse Propagation"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::{Answer, Error, Solution};
+use super::log::verbose;
+use super::timing;
+use super::util;
+use super::util::grid::Grid;
+use super::util::poly::Newton;
+
+/// Walking distances from `start` to every plot within `maxsteps` steps of it.
+fn distances(grid: &Grid<char>, start: (isize, isize), maxsteps: usize) -> HashMap<(isize, isize), usize> {
+    let mut dist = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(pos) = queue.pop_front() {
+        let steps = dist[&pos];
+        if steps == maxsteps {
+            continue;
+        }
+        // the garden repeats infinitely
+        for next in grid.neighbors4_wrapping(pos) {
+            if *grid.get_wrapped(next) != '#' && !dist.contains_key(&next) {
+                dist.insert(next, steps + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    dist
+}
+
+/// Plots reachable in exactly `steps` steps. Stepping back and forth wastes
+/// two steps, so those are the plots no further away with the same parity.
+fn count_reachable(dist: &HashMap<(isize, isize), usize>, steps: usize) -> usize {
+    dist.values().filter(|&&d| d <= steps && d % 2 == steps % 2).count()
+}
+
+/// Reads the garden, returning it along with the start.
+fn read_garden(input: &str) -> Result<(Grid<char>, (isize, isize)), Error> {
+    let input = input.trim();
+    let (grid, markers) = Grid::parse_with_markers(input, &['S'], |c| match c {
+        '.' | '#' => Some(c),
+        'S' => Some('.'),
+        _ => None,
+    })?;
+    let (row, col) = markers.one('S')?;
+    Ok((grid, (row as isize, col as isize)))
+}
+
+/// Counts the plots reachable in exactly `steps` steps on the infinitely
+/// repeating garden.
+fn reachable(input: &str, steps: usize) -> Result<Answer, Error> {
+    let (grid, start) = read_garden(input)?;
+    timing::parsed();
+
+    util::grid::print_grid(&grid, 1);
+    let dist = distances(&grid, start, steps);
+
+    Ok(Answer::from(count_reachable(&dist, steps)))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    reachable(input, 64)
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let (grid, start) = read_garden(input)?;
+    timing::parsed();
+
+    // the step counts repeat with the size of the garden, which only works out
+    // for a square one
+    if grid.width() != grid.height() {
+        return Err(Error::Invalid(format!("{}x{} garden is not square", grid.width(), grid.height())));
+    }
+    let size = grid.width();
+    let rem = 26501365 % size;
+
+    let samples: Vec<usize> = (0..4).map(|n| n * size + rem).collect();
+    let dist = distances(&grid, start, samples[3]);
+    let mut solutions = Vec::new();
+    for steps in samples {
+        let count = count_reachable(&dist, steps);
+        solutions.push(count as i128);
+        verbose!("End positions for {} steps: {:?}", steps, count);
+    }
+
+    // the count grows quadratically in whole gardens walked
+    let newton = Newton::fit(&solutions);
+    if newton.degree().filter(|&d| d <= 2).is_none() {
+        return Err(Error::Unsolvable(format!("{:?} do not grow quadratically", solutions)));
+    }
+    let x = (26501365 / size) as i128;
+
+    Ok(Answer::from(newton.eval(x)))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn title(&self) -> &'static str {
+        "Step Counter"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day21.example.txt");
+
+    #[test]
+    fn reachable_example() {
+        assert_eq!(reachable(EXAMPLE, 6).unwrap(), Answer::from(16));
+        assert_eq!(reachable(EXAMPLE, 10).unwrap(), Answer::from(50));
+        assert_eq!(reachable(EXAMPLE, 50).unwrap(), Answer::from(1594));
+    }
+}
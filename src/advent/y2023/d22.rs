@@ -1,10 +1,12 @@
 use nom::bytes::complete::tag;
 use nom::character::complete::u32;
-use nom::multi::separated_list1;
 use nom::{sequence::separated_pair, IResult};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::parse::{lines, parse_complete, triple};
 #[allow(unused_imports)]
 use super::util;
 
@@ -63,35 +65,26 @@ fn stack_bricks(grid: &mut [Vec<Vec<usize>>], blocks: &mut Vec<Block>) {
 }
 
 fn parse_block(input: &str) -> IResult<&str, Block> {
-    let (rem, (start, end)) = separated_pair(
-        separated_list1(tag(","), u32),
-        tag("~"),
-        separated_list1(tag(","), u32),
-    )(input)?;
+    let (rem, (start, end)) = separated_pair(triple(u32, ","), tag("~"), triple(u32, ","))(input)?;
     Ok((
         rem,
         Block {
             id: rem.len() + 1,
-            start: (start[0] as usize, start[1] as usize, start[2] as usize),
-            end: (end[0] as usize, end[1] as usize, end[2] as usize),
+            start: (start.0 as usize, start.1 as usize, start.2 as usize),
+            end: (end.0 as usize, end.1 as usize, end.2 as usize),
         },
     ))
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Block>> {
-    separated_list1(tag("\n"), parse_block)(input)
+    lines(parse_block)(input)
 }
 
-pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
 
-    let (rem, mut blocks) = parse_input(&input).unwrap();
-    if !rem.is_empty() {
-        return Err(Box::new(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "Input not fully consumed",
-        )));
-    }
+    let mut blocks = parse_complete(input, parse_input)?;
+    timing::parsed();
 
     let zmax = blocks
         .iter()
@@ -150,20 +143,14 @@ pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    println!("Removable blocks: {}", removable);
-    Ok(())
+    Ok(Answer::from(removable))
 }
 
-pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
 
-    let (rem, mut blocks) = parse_input(&input).unwrap();
-    if !rem.is_empty() {
-        return Err(Box::new(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "Input not fully consumed",
-        )));
-    }
+    let mut blocks = parse_complete(input, parse_input)?;
+    timing::parsed();
 
     let zmax = blocks
         .iter()
@@ -202,12 +189,7 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
         }
-        block_stands_on.insert(block.id, stands_on);
-    }
-
-    for block in &blocks {
-        let blockdeps = block_stands_on.get(&block.id).unwrap();
-        for dep in blockdeps {
+        for dep in &stands_on {
             if let Some(hs) = block_stands_below.get_mut(dep) {
                 hs.insert(block.id);
             } else {
@@ -216,6 +198,7 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
                 block_stands_below.insert(*dep, hs);
             }
         }
+        block_stands_on.insert(block.id, stands_on);
     }
 
     let mut total_fallout = 0;
@@ -240,6 +223,29 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
         total_fallout += blocks_dropped.len() - 1;
     }
 
-    println!("Total fallout from blocks: {}", total_fallout);
-    Ok(())
+    Ok(Answer::from(total_fallout))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn title(&self) -> &'static str {
+        "Sand Slabs"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
 }
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use super::{Answer, Error, Solution};
+use super::log::verbose;
+use super::timing;
+use super::util::graph::{Graph, NodeId};
+use super::util::grid::{Direction, Grid};
+
+/// Whether `tile` can be stepped onto moving in `dir`. Slopes cannot be
+/// climbed, unless the `slopes` are being ignored.
+fn passable(tile: char, dir: Direction, slopes: bool) -> bool {
+    match (tile, dir) {
+        ('#', _) => false,
+        _ if !slopes => true,
+        ('<', Direction::East) => false,
+        ('>', Direction::West) => false,
+        ('^', Direction::South) => false,
+        ('v', Direction::North) => false,
+        _ => true,
+    }
+}
+
+const START: NodeId = 0;
+const GOAL: NodeId = 1;
+
+/// The trails reduced to their junctions, the start and the goal, with an edge
+/// per corridor weighing its length. Nothing leads back into the start or out
+/// of the goal, as no hike would take it.
+fn junctions(grid: &Grid<char>, slopes: bool) -> Result<Graph<u32>, Error> {
+    let too_small = || Error::Invalid("the map is too small for a start and a goal".to_owned());
+    let start = (0, 1);
+    let goal = (
+        grid.height().checked_sub(1).ok_or_else(too_small)?,
+        grid.width().checked_sub(2).ok_or_else(too_small)?,
+    );
+    if grid.get(start) != Some(&'.') || grid.get(goal) != Some(&'.') {
+        return Err(Error::Invalid("no gaps for the start and the goal".to_owned()));
+    }
+    let mut ids: HashMap<(usize, usize), NodeId> = HashMap::from([(start, START), (goal, GOAL)]);
+    for (pos, &tile) in grid.indexed() {
+        if tile != '#' && grid.neighbors4(pos).filter(|&n| grid[n] != '#').count() > 2 {
+            ids.insert(pos, ids.len());
+        }
+    }
+
+    let mut graph = Graph::directed();
+    graph.add_node(ids.len() - 1);
+    for (&from, &id) in ids.iter().filter(|&(_, &id)| id != GOAL) {
+        for dir in Direction::ALL {
+            let Some(mut pos) = grid.step(from, dir).filter(|&p| passable(grid[p], dir, slopes)) else {
+                continue;
+            };
+            let (mut prev, mut length) = (from, 1);
+            loop {
+                if let Some(&to) = ids.get(&pos) {
+                    if to != START {
+                        graph.add_edge(id, to, length);
+                    }
+                    break;
+                }
+                // away from a junction there is at most one way on
+                let next = Direction::ALL
+                    .into_iter()
+                    .filter_map(|d| grid.step(pos, d).filter(|&n| n != prev && passable(grid[n], d, slopes)))
+                    .next();
+                let Some(next) = next else {
+                    break;
+                };
+                (prev, pos, length) = (pos, next, length + 1);
+            }
+        }
+    }
+    verbose!("{} junctions", graph.len());
+    Ok(graph)
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let grid = Grid::parse(input, |c| "#.<>^v".contains(c).then_some(c))?;
+    timing::parsed();
+
+    // with slopes only walked downhill there are no loops, so the longest
+    // path can be built up in topological order
+    let graph = junctions(&grid, true)?;
+    let order = graph
+        .toposort()
+        .ok_or(Error::Unsolvable("the slopes allow walking in circles".to_owned()))?;
+    let mut longest: Vec<Option<u32>> = vec![None; graph.len()];
+    longest[START] = Some(0);
+    for id in order {
+        let Some(distance) = longest[id] else {
+            continue;
+        };
+        for &(to, length) in graph.edges(id) {
+            longest[to] = longest[to].max(Some(distance + length));
+        }
+    }
+    let distance = longest[GOAL].ok_or(Error::Unsolvable("the goal cannot be reached".to_owned()))?;
+
+    Ok(Answer::from(distance))
+}
+
+fn longest_path_rec(graph: &Graph<u32>, visited: &mut [bool], node: NodeId) -> Option<u32> {
+    if node == GOAL {
+        return Some(0);
+    }
+    visited[node] = true;
+    let mut max_dist = None;
+    for &(next, length) in graph.edges(node) {
+        if visited[next] {
+            continue;
+        }
+        if let Some(dist) = longest_path_rec(graph, visited, next) {
+            max_dist = max_dist.max(Some(dist + length));
+        }
+    }
+    visited[node] = false;
+    max_dist
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let grid = Grid::parse(input, |c| "#.<>^v".contains(c).then_some(c))?;
+    timing::parsed();
+
+    let graph = junctions(&grid, false)?;
+    let mut visited = vec![false; graph.len()];
+    let max_dist = longest_path_rec(&graph, &mut visited, START)
+        .ok_or(Error::Unsolvable("the goal cannot be reached".to_owned()))?;
+
+    Ok(Answer::from(max_dist))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn title(&self) -> &'static str {
+        "A Long Walk"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -1,11 +1,14 @@
 use nom::bytes::complete::tag;
-use nom::character::complete::i64;
-use nom::multi::{many1, separated_list1};
+use nom::character::complete::{i64, space1};
 use nom::sequence::{separated_pair, tuple};
 use nom::IResult;
 
 use nalgebra::{matrix, vector};
 
+use super::{Answer, Error, Solution};
+use super::timing;
+use super::util::parse::{lines, parse_complete, triple};
+
 #[derive(Debug, Clone)]
 struct Hailstone {
     p: (f64, f64, f64),
@@ -16,21 +19,14 @@ struct Hailstone {
 }
 
 fn parse_trip(input: &str) -> IResult<&str, (f64, f64, f64)> {
-    let (rem, v) = separated_list1(tuple((tag(","), many1(tag(" ")))), i64)(input)?;
-    if v.len() != 3 {
-        Err(nom::Err::Error(nom::error::ParseError::from_error_kind(
-            input,
-            nom::error::ErrorKind::SeparatedList,
-        )))
-    } else {
-        Ok((rem, (v[0] as f64, v[1] as f64, v[2] as f64)))
-    }
+    let (rem, (x, y, z)) = triple(i64, ",")(input)?;
+    Ok((rem, (x as f64, y as f64, z as f64)))
 }
 
 fn parse_hailstone(input: &str) -> IResult<&str, Hailstone> {
     let (rem, (p, v)) = separated_pair(
         parse_trip,
-        tuple((many1(tag(" ")), tag("@"), many1(tag(" ")))),
+        tuple((space1, tag("@"), space1)),
         parse_trip,
     )(input)?;
     // p.0 = x + t * v.0
@@ -54,16 +50,15 @@ fn parse_hailstone(input: &str) -> IResult<&str, Hailstone> {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Hailstone>> {
-    let (rem, v) = separated_list1(tag("\n"), parse_hailstone)(input)?;
-    Ok((rem, v))
+    lines(parse_hailstone)(input)
 }
 
-pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
-    let (_, hailstones) = parse_input(&input).unwrap();
-    let area_start: f64 = 200000000000000.0;
-    let area_end: f64 = 400000000000000.0;
-
+/// Counts the pairs of hailstone paths that cross in the future within the
+/// square test area spanning `area_start..=area_end` on both axes.
+fn count_intersections(input: &str, area_start: f64, area_end: f64) -> Result<Answer, Error> {
+    let input = input.trim();
+    let hailstones = parse_complete(input, parse_input)?;
+    timing::parsed();
     let mut intersecting_points = 0;
     for (i, hs1) in hailstones.iter().enumerate() {
         for hs2 in hailstones[(i + 1)..].iter() {
@@ -94,12 +89,15 @@ pub fn pt1(path: String) -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    println!("Intersecting Points: {}", intersecting_points);
 
-    Ok(())
+    Ok(Answer::from(intersecting_points))
 }
 
-pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
+fn pt1(input: &str) -> Result<Answer, Error> {
+    count_intersections(input, 200000000000000.0, 400000000000000.0)
+}
+
+fn pt2(input: &str) -> Result<Answer, Error> {
     // Solution heavily based on https://gitlab.com/silmeth/advent-of-code-2023/-/blob/main/day-24/src/lib.rs
     // Let the rock stone be defined as pos + velocity: Rp and Rv
     // Since the rock crosses all hailstones then for any hailstone hs
@@ -139,8 +137,12 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
     //
     // Where the above is a system with 4 unknowns. We can solve this using linear algebra with
     // Gaussian Elimination
-    let input: String = std::fs::read_to_string(path)?.trim().parse()?;
-    let (_, hs) = parse_input(&input).unwrap();
+    let input = input.trim();
+    let hs = parse_complete(input, parse_input)?;
+    timing::parsed();
+    if hs.len() < 5 {
+        return Err(Error::Invalid("At least 5 hailstones are needed".to_owned()));
+    }
 
     // generate a matrix for a system with 4 equations to solve for the 4 unknowns
     // This requires 5 hailstones
@@ -184,6 +186,41 @@ pub fn pt2(path: String) -> Result<(), Box<dyn std::error::Error>> {
 
     let rv_z = (z_eq_lh * z_eq_rh)[(0,0)];
 
-    println!("Sum of starting coordinates: {}", (rp_x + rp_y + rv_z) as u64);
-    Ok(())
+    Ok(Answer::from((rp_x + rp_y + rv_z) as u64))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn title(&self) -> &'static str {
+        "Never Tell Me The Odds"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day24.example.txt");
+
+    #[test]
+    fn count_intersections_example() {
+        assert_eq!(count_intersections(EXAMPLE, 7.0, 27.0).unwrap(), Answer::from(2));
+    }
 }
@@ -0,0 +1,75 @@
+use nom::character::complete::alpha1;
+use nom::IResult;
+
+use super::{Answer, Error, Solution};
+use super::log::verbose;
+use super::timing;
+use super::util::graph::{Graph, Interner};
+use super::util::parse::{edges, lines, parse_complete};
+
+fn parse_line(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    edges(alpha1, ": ", alpha1, " ")(input)
+}
+
+/// The wiring as an undirected graph where every wire weighs 1.
+fn parse_input(input: &str) -> IResult<&str, (Interner<&str>, Graph<u64>)> {
+    let (rem, entries) = lines(parse_line)(input)?;
+    let mut names = Interner::new();
+    let mut graph = Graph::undirected();
+    for (component, neighbors) in entries {
+        let from = names.intern(component);
+        for neighbor in neighbors {
+            graph.add_edge(from, names.intern(neighbor), 1);
+        }
+    }
+    Ok((rem, (names, graph)))
+}
+
+fn pt1(input: &str) -> Result<Answer, Error> {
+    let input = input.trim();
+    let (names, graph) = parse_complete(input, parse_input)?;
+    timing::parsed();
+    let components = graph.components();
+    if components.len() != 1 {
+        return Err(Error::Invalid(format!("the wiring is already in {} pieces", components.len())));
+    }
+    verbose!("Initial graph size: {}", graph.len());
+
+    let (wires, side) = graph
+        .min_cut()
+        .ok_or(Error::Invalid("fewer than two components".to_owned()))?;
+    if wires != 3 {
+        return Err(Error::Unsolvable(format!("the smallest cut is {} wires, not 3", wires)));
+    }
+    let cut: Vec<&str> = side.iter().take(5).map(|&id| *names.name(id)).collect();
+    verbose!("Cut off {} components, starting {:?}", side.len(), cut);
+    Ok(Answer::from(side.len() * (graph.len() - side.len())))
+}
+
+fn pt2(_input: &str) -> Result<Answer, Error> {
+    Ok(Answer::from("Merry Christmas"))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn title(&self) -> &'static str {
+        "Snowverload"
+    }
+
+    fn pt1(&self, input: &str) -> Result<Answer, Error> {
+        pt1(input)
+    }
+
+    fn pt2(&self, input: &str) -> Result<Answer, Error> {
+        pt2(input)
+    }
+}
@@ -0,0 +1,57 @@
+//! The puzzles of Advent of Code 2023.
+
+use super::{log, timing, util, Answer, Error, Solution};
+
+mod d01;
+mod d02;
+mod d03;
+mod d04;
+mod d05;
+mod d06;
+mod d07;
+mod d08;
+mod d09;
+mod d10;
+mod d11;
+mod d12;
+mod d13;
+mod d14;
+mod d15;
+mod d16;
+mod d17;
+mod d18;
+mod d19;
+mod d20;
+mod d21;
+mod d22;
+mod d23;
+mod d24;
+mod d25;
+
+pub(super) static SOLUTIONS: &[&dyn Solution] = &[
+    &d01::Day01,
+    &d02::Day02,
+    &d03::Day03,
+    &d04::Day04,
+    &d05::Day05,
+    &d06::Day06,
+    &d07::Day07,
+    &d08::Day08,
+    &d09::Day09,
+    &d10::Day10,
+    &d11::Day11,
+    &d12::Day12,
+    &d13::Day13,
+    &d14::Day14,
+    &d15::Day15,
+    &d16::Day16,
+    &d17::Day17,
+    &d18::Day18,
+    &d19::Day19,
+    &d20::Day20,
+    &d21::Day21,
+    &d22::Day22,
+    &d23::Day23,
+    &d24::Day24,
+    &d25::Day25,
+];
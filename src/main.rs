@@ -1,24 +1,264 @@
-mod advent;
 use clap::Parser;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use advent2023::advent;
+use advent::check::Expected;
+use advent::input;
+use advent::log::{self, Level};
+use advent::runner::Run;
+use advent::timing;
 
 #[derive(Parser, Debug)]
-#[command(version)]
+#[command(version, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Year whose exercises to run, list or generate
+    #[arg(short, long, global = true, default_value_t = advent::DEFAULT_YEAR)]
+    year: u16,
+
     /// Exercise to run
-    #[arg(short, long)]
-    exercise: u8,
+    #[arg(short, long, required_unless_present_any = ["list", "all", "days"])]
+    exercise: Option<u8>,
+
+    /// Part to run. Both parts are run with --all or --days unless given
+    #[arg(short, long, required_unless_present_any = ["list", "all", "days"])]
+    part: Option<u8>,
 
-    /// Part to run
+    /// Datafile to use, `-` for stdin, or the directory holding dayNN.txt files with
+    /// --all or --days. Defaults to inputs/dayNN.txt, or $ADVENT_INPUTS/dayNN.txt when set,
+    /// with years other than the default in a YYYY subdirectory
     #[arg(short, long)]
-    part: u8,
+    data: Option<String>,
+
+    /// Use the example input, dayNN.example.txt, from the inputs directory
+    #[arg(long)]
+    example: bool,
 
-    /// Datafile to use
+    /// List the available exercises
     #[arg(short, long)]
-    data: String,
+    list: bool,
+
+    /// Run every exercise and print a timing table
+    #[arg(short, long, conflicts_with_all = ["exercise", "days"])]
+    all: bool,
+
+    /// Run a range of exercises, e.g. 5..=12, and print a timing table
+    #[arg(long, value_parser = advent::runner::parse_days, conflicts_with = "exercise")]
+    days: Option<RangeInclusive<u8>>,
+
+    /// Compare answers against the answers file and report pass/fail/missing
+    #[arg(long)]
+    check: bool,
+
+    /// Answers file used by --check
+    #[arg(long, default_value = advent::check::DEFAULT_ANSWERS, requires = "check")]
+    answers: PathBuf,
+
+    /// Show how the solvers get their answers on stderr; -vv shows every step
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Only print the answers and any errors
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Run the part N times, after a few untimed warm-up runs, and report
+    /// timing statistics for its parse and solve phases
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..),
+          conflicts_with_all = ["all", "days", "check"])]
+    bench: Option<u64>,
+
+    /// Worker threads for the solvers. Defaults to one per core; 1 runs
+    /// everything sequentially. Giving it also runs the days of --all or --days
+    /// concurrently, which otherwise run one at a time to keep their timings
+    /// apart
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    threads: Option<u64>,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "check")]
+    format: Format,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Generate the module for a new day, with an empty example input and an
+    /// example test that fails until its answers are filled in, and register it
+    NewDay {
+        /// Day to generate
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Puzzle title. Defaults to "Day NN"
+        #[arg(long)]
+        title: Option<String>,
+    },
 }
 
-fn main()  -> Result<(), Box<dyn std::error::Error>> {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum Format {
+    /// The answer, or a table of answers and timings
+    Text,
+    /// One JSON object per run: day, part, input, answer, elapsed_ms and error
+    Json,
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
+    log::set_level(Level::from_flags(args.quiet, args.verbose));
+    if let Some(threads) = args.threads {
+        advent::set_threads(threads as usize);
+    }
+
+    if let Some(Command::NewDay { day, title }) = args.command {
+        let title = title.unwrap_or_else(|| format!("Day {:02}", day));
+        return new_day(args.year, day, &title);
+    }
+
+    if args.list {
+        for solution in advent::solutions(args.year) {
+            println!("{:>2}: {}", solution.day(), solution.title());
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let expected = if args.check {
+        match Expected::load(&args.answers) {
+            Ok(expected) => Some(expected),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
+    if args.all || args.days.is_some() {
+        let days = args.days.unwrap_or(u8::MIN..=u8::MAX);
+        let parts = match args.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        };
+        let dir = args
+            .data
+            .map_or_else(|| input::year_dir(&input::inputs_dir(), args.year), PathBuf::from);
+        if advent::solutions(args.year).next().is_none() {
+            eprintln!("Error: {}", advent::Error::NoSuchYear(args.year));
+            return ExitCode::FAILURE;
+        }
+        let parallel = args.threads.is_some();
+        let runs = advent::runner::run_all(args.year, &days, &parts, &dir, args.example, parallel);
+        if let Some(expected) = expected {
+            let passed = advent::check::print_report(&runs, &expected, args.example);
+            return if passed {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            };
+        }
+        match args.format {
+            Format::Text => advent::runner::print_table(&runs),
+            Format::Json => advent::runner::print_json(&runs),
+        }
+        return if runs.iter().all(|r| r.result.is_ok()) {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+
+    let (exercise, part) = match (args.exercise, args.part) {
+        (Some(exercise), Some(part)) => (exercise, part),
+        _ => unreachable!("clap enforces the arguments unless listing"),
+    };
+    let data = match args.data {
+        Some(data) => Ok(data),
+        None => {
+            let dir = input::year_dir(&input::inputs_dir(), args.year);
+            input::find_input(&dir, exercise, part, args.example)
+        }
+    };
+    let data = data.and_then(|path| Ok((input::read_input(&path)?, path)));
+    if let Some(runs) = args.bench {
+        return bench(args.year, exercise, part, data, runs as usize, args.format);
+    }
+    let run = match (advent::find(args.year, exercise), data) {
+        (Ok(solution), Ok((input, path))) => Run::new(solution, part, &path, &input),
+        (Err(e), _) | (_, Err(e)) => Run::failed(args.year, exercise, part, e),
+    };
+    if let Some(expected) = expected {
+        return if advent::check::print_report(&[run], &expected, args.example) {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+    if args.format == Format::Json {
+        advent::runner::print_json(std::slice::from_ref(&run));
+        return if run.result.is_ok() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+    match run.result {
+        Ok(answer) => {
+            println!("{}", answer);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Benchmarks a single part on `data`, its input and path.
+fn bench(
+    year: u16,
+    exercise: u8,
+    part: u8,
+    data: Result<(String, String), advent::Error>,
+    runs: usize,
+    format: Format,
+) -> ExitCode {
+    let warmup = (runs / 10).max(1);
+    let result = advent::find(year, exercise).and_then(|solution| {
+        let (input, path) = data?;
+        timing::bench(solution, part, &path, &input, warmup, runs)
+    });
+    match result {
+        Ok(bench) => {
+            match format {
+                Format::Text => bench.print(),
+                Format::Json => println!("{}", bench.to_json()),
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    advent::run(args.exercise, args.part, args.data)
+/// Scaffolds `day` of `year` in the crate this binary was built from.
+fn new_day(year: u16, day: u8, title: &str) -> ExitCode {
+    match advent::scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day, title) {
+        Ok(touched) => {
+            for path in touched {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,101 @@
+//! Runs every day against the example from its puzzle description, kept in
+//! `inputs/` as `dayNN.example.txt`, and checks the published answers. Each
+//! year's days are in a module of their own.
+//!
+//! Days whose examples use different parameters from the real puzzle (11, 21
+//! and 24) cover those in unit tests next to the solver instead.
+
+use std::path::Path;
+
+use advent2023::advent::{self, input, Answer};
+
+fn example(year: u16, day: u8, part: u8) -> Answer {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(input::DEFAULT_INPUTS);
+    let path = input::find_input(&input::year_dir(&dir, year), day, part, true).unwrap();
+    let input = input::read_input(&path).unwrap();
+    match advent::run(year, day, part, &input) {
+        Ok(answer) => answer,
+        Err(e) => panic!("{} day {} pt{}: {}", year, day, part, e),
+    }
+}
+
+macro_rules! examples {
+    ($year:expr; $($name:ident: $day:expr => $pt1:expr, $pt2:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                if let Some(expected) = $pt1 {
+                    assert_eq!(example($year, $day, 1), Answer::from(expected), "pt1");
+                }
+                if let Some(expected) = $pt2 {
+                    assert_eq!(example($year, $day, 2), Answer::from(expected), "pt2");
+                }
+            }
+        )*
+    };
+}
+
+const NONE: Option<i64> = None;
+
+mod y2023 {
+    use super::*;
+
+    examples! {
+        2023;
+        day01: 1 => Some(142), Some(281);
+        day02: 2 => Some(8), Some(2286);
+        day03: 3 => Some(4361), Some(467835);
+        day04: 4 => Some(13), Some(30);
+        day05: 5 => Some(35), Some(46);
+        day06: 6 => Some(288), Some(71503);
+        day07: 7 => Some(6440), Some(5905);
+        day08: 8 => Some(2), Some(6);
+        day09: 9 => Some(114), Some(2);
+        day10: 10 => Some(8), Some(10);
+        day11: 11 => Some(374), NONE;
+        day12: 12 => Some(21), Some(525152);
+        day13: 13 => Some(405), Some(400);
+        day14: 14 => Some(136), Some(64);
+        day15: 15 => Some(1320), Some(145);
+        day16: 16 => Some(46), Some(51);
+        day17: 17 => Some(102), Some(94);
+        day18: 18 => Some(62), Some(952408144115_i64);
+        day19: 19 => Some(19114), Some(167409079868000_i64);
+        // The example has no `rx` module, so there is no second part to check.
+        day20: 20 => Some(11687500), NONE;
+        day21: 21 => NONE, NONE;
+        day22: 22 => Some(5), Some(7);
+        day23: 23 => Some(94), Some(154);
+        day24: 24 => NONE, Some(47);
+        day25: 25 => Some(54), Some("Merry Christmas");
+    }
+}
+
+#[test]
+fn literal_input() {
+    let input = "1abc2\npqr3stu8vwx\n";
+    assert_eq!(
+        advent::run(advent::DEFAULT_YEAR, 1, 1, input).unwrap(),
+        Answer::from(12 + 38)
+    );
+}
+
+#[test]
+fn buffered_input() {
+    let mut reader = "0 3 6 9 12 15\n".as_bytes();
+    let solution = advent::find(advent::DEFAULT_YEAR, 9).unwrap();
+    let answer = solution.run_reader(1, &mut reader).unwrap();
+    assert_eq!(answer, Answer::from(18));
+}
+
+#[test]
+fn unknown_year() {
+    assert!(matches!(
+        advent::run(1999, 1, 1, ""),
+        Err(advent::Error::NoSuchYear(1999))
+    ));
+    assert!(matches!(
+        advent::find(advent::DEFAULT_YEAR, 26),
+        Err(advent::Error::NoSuchExercise(26))
+    ));
+}